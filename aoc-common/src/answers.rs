@@ -0,0 +1,184 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{error, info, warn};
+
+use crate::{Answer, CommonArgs, Input, Part, Solution};
+
+/// Known answers recorded in an `answers.toml` file.
+///
+/// Answers are keyed by day, part and [`input_hash`] of the puzzle input, so answers of the
+/// example and the real input of the same day live next to each other:
+///
+/// ```toml
+/// [day-4.part-2]
+/// 2c5f0e3dbb6b7fa1 = "30"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>);
+
+impl Answers {
+    /// Loads answers from given file. Missing file is treated as an empty registry.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the file cannot be read or is not a valid answers file.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|error| AnswersError::Parse(path.to_owned(), error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(AnswersError::Read(path.to_owned(), error)),
+        }
+    }
+
+    /// Writes answers to given file, replacing its previous contents.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let contents = toml::to_string(self).map_err(AnswersError::Serialize)?;
+        std::fs::write(path, contents).map_err(|error| AnswersError::Write(path.to_owned(), error))
+    }
+
+    /// Recorded answer of given part for input with given hash.
+    #[must_use]
+    pub fn get(&self, day: u8, part: Part, hash: &str) -> Option<&str> {
+        self.0
+            .get(&day_key(day))?
+            .get(&part_key(part))?
+            .get(hash)
+            .map(String::as_str)
+    }
+
+    /// Records answer of given part for input with given hash, returning previously recorded one.
+    pub fn insert(&mut self, day: u8, part: Part, hash: String, answer: Answer) -> Option<String> {
+        self.0
+            .entry(day_key(day))
+            .or_default()
+            .entry(part_key(part))
+            .or_default()
+            .insert(hash, answer.to_string())
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day-{day}")
+}
+
+fn part_key(part: Part) -> String {
+    format!("part-{part}")
+}
+
+/// Stable 64-bit FNV-1a hash of puzzle input, rendered as 16 hexadecimal digits.
+#[must_use]
+pub fn input_hash(contents: &str) -> String {
+    let hash = contents
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    format!("{hash:016x}")
+}
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("Cannot read answers file `{0}`: {1}")]
+    Read(PathBuf, #[source] io::Error),
+    #[error("Cannot parse answers file `{0}`: {1}")]
+    Parse(PathBuf, #[source] toml::de::Error),
+    #[error("Cannot serialize answers: {0}")]
+    Serialize(#[source] toml::ser::Error),
+    #[error("Cannot write answers file `{0}`: {1}")]
+    Write(PathBuf, #[source] io::Error),
+}
+
+/// Checks computed answers against the registry or records them, as requested by `--check`
+/// and `--record`.
+#[derive(Debug)]
+pub struct Verifier {
+    path: PathBuf,
+    answers: Answers,
+    record: bool,
+    mismatches: usize,
+}
+
+impl Verifier {
+    /// Loads the registry when `--check` or `--record` was given. Returns `None` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the answers file cannot be loaded.
+    pub fn from_args(args: &CommonArgs) -> Result<Option<Self>, AnswersError> {
+        if !args.check && !args.record {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            answers: Answers::load(&args.answers)?,
+            path: args.answers.clone(),
+            record: args.record,
+            mismatches: 0,
+        }))
+    }
+
+    /// Compares solution with the recorded answer, or records it in record mode.
+    pub fn verify(&mut self, day: u8, input: &Input, solution: &Solution) {
+        let hash = input_hash(&input.contents);
+        let name = input.name();
+        let part = solution.part;
+
+        if self.record {
+            match self.answers.insert(day, part, hash, solution.answer) {
+                Some(previous) if previous != solution.answer.to_string() => warn!(
+                    "Day {day} part {part} ({name}): replacing recorded {previous} with {}.",
+                    solution.answer
+                ),
+                _ => info!(
+                    "Day {day} part {part} ({name}): recorded {}.",
+                    solution.answer
+                ),
+            }
+            return;
+        }
+
+        match self.answers.get(day, part, &hash) {
+            Some(expected) if expected == solution.answer.to_string() => {
+                info!("Day {day} part {part} ({name}): {expected} is correct.");
+            }
+            Some(expected) => {
+                error!(
+                    "Day {day} part {part} ({name}): expected {expected}, got {}.",
+                    solution.answer
+                );
+                self.mismatches += 1;
+            }
+            None => warn!("Day {day} part {part} ({name}): no recorded answer."),
+        }
+    }
+
+    /// Saves recorded answers. Fails when any checked answer did not match or saving failed.
+    #[must_use]
+    pub fn finish(self) -> ExitCode {
+        if self.record {
+            if let Err(error) = self.answers.save(&self.path) {
+                error!("{error}");
+                return ExitCode::FAILURE;
+            }
+        }
+
+        if self.mismatches == 0 {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
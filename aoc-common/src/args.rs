@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+use clap::{ArgAction, Args};
+
+use crate::{Format, Mode, Parts};
+
+/// Arguments accepted by every day.
+///
+/// Days embed it in their own `Args` with `#[command(flatten)]` and add puzzle specific flags next to it.
+#[derive(Args, Clone, Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct CommonArgs {
+    /// Puzzle input file. Standard input is read when it's `-` or when no other input is given.
+    #[arg(short, long, value_name = "<FILE>", conflicts_with_all = ["input_str", "dir"])]
+    pub filename: Option<PathBuf>,
+    /// Puzzle input given directly on command line.
+    #[arg(long, value_name = "<INPUT>", conflicts_with = "dir")]
+    pub input_str: Option<String>,
+    /// Directory with puzzle inputs. Every file in it is solved separately.
+    #[arg(long, value_name = "<DIR>")]
+    pub dir: Option<PathBuf>,
+    /// Part of the puzzle to solve.
+    #[arg(short, long, value_name = "<PART>", value_enum, default_value_t)]
+    pub part: Parts,
+    /// Format of printed answers.
+    #[arg(long, value_name = "<FORMAT>", value_enum, default_value_t)]
+    pub format: Format,
+    /// Rejects any fragment of input which is not understood, e.g. blank lines or unknown sections.
+    #[arg(long, conflicts_with = "lenient")]
+    pub strict: bool,
+    /// Drops malformed records instead of failing and reports everything dropped as warnings.
+    #[arg(long)]
+    pub lenient: bool,
+    /// Compares answers with ones recorded in the answers file and fails on any mismatch.
+    #[arg(long, conflicts_with = "record")]
+    pub check: bool,
+    /// Stores computed answers in the answers file.
+    #[arg(long)]
+    pub record: bool,
+    /// File with known answers used by `--check` and `--record`.
+    #[arg(long, value_name = "<FILE>", default_value = "answers.toml")]
+    pub answers: PathBuf,
+    /// Increases logging verbosity. With at least one `-v` time spent on every part is reported.
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+}
+
+impl CommonArgs {
+    /// Parsing mode selected by `--strict` or `--lenient`.
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        if self.strict {
+            Mode::Strict
+        } else if self.lenient {
+            Mode::Lenient
+        } else {
+            Mode::Normal
+        }
+    }
+}
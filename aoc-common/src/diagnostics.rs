@@ -0,0 +1,93 @@
+use crate::ParseError;
+
+/// How parsers treat fragments of input they cannot use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Malformed records are errors, fragments carrying no data (e.g. blank lines) are ignored.
+    #[default]
+    Normal,
+    /// Every fragment of input has to be understood, anything ignored otherwise is an error.
+    Strict,
+    /// Malformed records and ignored fragments are dropped and reported as warnings.
+    Lenient,
+}
+
+/// Parsing mode together with everything dropped from the input so far.
+#[derive(Clone, Debug, Default)]
+pub struct Diagnostics {
+    pub mode: Mode,
+    dropped: Vec<ParseError>,
+}
+
+impl Diagnostics {
+    #[must_use]
+    pub fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            dropped: Vec::new(),
+        }
+    }
+
+    /// Handles result of parsing a single record. Lenient mode drops malformed record (returning
+    /// `None`), other modes fail with its error.
+    ///
+    /// # Errors
+    ///
+    /// Fails with error of malformed record unless in lenient mode.
+    pub fn record<T>(&mut self, result: Result<T, ParseError>) -> Result<Option<T>, ParseError> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(error) if self.mode == Mode::Lenient => {
+                self.dropped.push(error);
+                Ok(None)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Handles fragment of input which parser ignores. Strict mode rejects it, lenient mode reports it.
+    ///
+    /// # Errors
+    ///
+    /// Fails with given error in strict mode.
+    pub fn ignore(&mut self, error: ParseError) -> Result<(), ParseError> {
+        match self.mode {
+            Mode::Normal => Ok(()),
+            Mode::Strict => Err(error),
+            Mode::Lenient => {
+                self.dropped.push(error);
+                Ok(())
+            }
+        }
+    }
+
+    /// Runs `parse` on `fragment` of `input`, so that errors it reports point into `input`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with rebased error of `parse`.
+    pub fn within<T>(
+        &mut self,
+        input: &str,
+        fragment: &str,
+        parse: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let start = self.dropped.len();
+        let result = parse(self);
+
+        for error in &mut self.dropped[start..] {
+            *error = error.clone().within(input, fragment);
+        }
+        result.map_err(|error| error.within(input, fragment))
+    }
+
+    /// Everything dropped from the input in order of appearance, each fragment reported once even
+    /// when parsed repeatedly (e.g. by both parts).
+    #[must_use]
+    pub fn dropped(&self) -> Vec<&ParseError> {
+        let mut dropped = self.dropped.iter().collect::<Vec<_>>();
+        dropped.sort_by_key(|error| (error.span.start, error.span.end));
+        dropped.dedup();
+        dropped
+    }
+}
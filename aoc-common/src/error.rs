@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use thiserror::Error;
+
+/// Byte range of puzzle input an error refers to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    #[must_use]
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Span of `fragment`, which has to be a slice of `input` (e.g. a line or a token of it).
+    ///
+    /// # Panics
+    ///
+    /// Panics when `fragment` doesn't lie within `input`.
+    #[must_use]
+    pub fn of(input: &str, fragment: &str) -> Self {
+        let start = (fragment.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(
+            start <= input.len() && start + fragment.len() <= input.len(),
+            "fragment is not a slice of input"
+        );
+
+        Self::new(start, start + fragment.len())
+    }
+
+    /// Empty span right after the last non-blank character of `input`, used when something is missing.
+    #[must_use]
+    pub fn end_of(input: &str) -> Self {
+        let end = input.trim_end().len();
+        Self::new(end, end)
+    }
+
+    /// 1-based line and column (counted in characters) where the span starts.
+    #[must_use]
+    pub fn line_column(&self, input: &str) -> (usize, usize) {
+        let start = self.start.min(input.len());
+        let before = &input[..start];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        (line, input[line_start..start].chars().count() + 1)
+    }
+}
+
+/// Error found in puzzle input, pointing at the offending fragment of it.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("{message}")]
+pub struct ParseError {
+    pub span: Span,
+    pub message: String,
+}
+
+impl ParseError {
+    #[must_use]
+    pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+        }
+    }
+
+    /// Error pointing at `fragment` of `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `fragment` doesn't lie within `input`.
+    #[must_use]
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        Self::new(Span::of(input, fragment), message)
+    }
+
+    /// Error about something missing at the end of `input`.
+    #[must_use]
+    pub fn end_of(input: &str, message: impl Into<String>) -> Self {
+        Self::new(Span::end_of(input), message)
+    }
+
+    /// Rebases error found while parsing `fragment` so that it points into whole `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `fragment` doesn't lie within `input`.
+    #[must_use]
+    pub fn within(self, input: &str, fragment: &str) -> Self {
+        let offset = Span::of(input, fragment).start;
+
+        Self::new(
+            Span::new(self.span.start + offset, self.span.end + offset),
+            self.message,
+        )
+    }
+
+    /// Renders the error together with the offending line of `input` and a caret under the span:
+    ///
+    /// ```text
+    /// error: invalid game id `x`
+    ///  --> input.txt:3:6
+    ///   |
+    /// 3 | Game x: 3 blue
+    ///   |      ^
+    /// ```
+    #[must_use]
+    pub fn render(&self, name: &str, input: &str) -> String {
+        self.render_as("error", name, input)
+    }
+
+    /// Renders the error like [`ParseError::render`], but as a warning about dropped input.
+    #[must_use]
+    pub fn render_warning(&self, name: &str, input: &str) -> String {
+        self.render_as("warning", name, input)
+    }
+
+    fn render_as(&self, severity: &str, name: &str, input: &str) -> String {
+        let (line, column) = self.span.line_column(input);
+        let text = input.lines().nth(line - 1).unwrap_or_default();
+        let width = self
+            .span
+            .end
+            .min(input.len())
+            .saturating_sub(self.span.start)
+            .min(text.len());
+        let carets = input
+            .get(self.span.start..self.span.start + width)
+            .map_or(1, |fragment| fragment.chars().count().max(1));
+        let gutter = line.to_string().len();
+
+        let mut rendered = format!("{severity}: {}\n", self.message);
+        let _ = writeln!(rendered, "{:gutter$}--> {name}:{line}:{column}", "");
+        let _ = writeln!(rendered, "{:gutter$} |", "");
+        let _ = writeln!(rendered, "{line} | {text}");
+        let _ = write!(
+            rendered,
+            "{:gutter$} | {:>indent$}",
+            "",
+            "^".repeat(carets),
+            indent = column - 1 + carets
+        );
+
+        rendered
+    }
+}
@@ -0,0 +1,113 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+use tracing::debug;
+
+use crate::CommonArgs;
+
+/// Single puzzle input together with information where it came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    pub source: Source,
+    pub contents: String,
+}
+
+impl Input {
+    /// Header separating results of consecutive inputs. Only inputs read from directory have one.
+    #[must_use]
+    pub fn header(&self) -> Option<String> {
+        match &self.source {
+            Source::DirEntry(path) => Some(format!("==> {} <==", path.display())),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    Inline,
+    File(PathBuf),
+    DirEntry(PathBuf),
+}
+
+impl CommonArgs {
+    /// Reads all puzzle inputs selected on command line.
+    ///
+    /// Exactly one input is returned for file, inline string and standard input.
+    /// Directory yields one input per regular file it contains, sorted by file name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InputError`] when any of the inputs cannot be read.
+    pub fn read_inputs(&self) -> Result<Vec<Input>, InputError> {
+        if let Some(dir) = self.dir.as_ref() {
+            return read_dir(dir);
+        }
+
+        if let Some(contents) = self.input_str.as_ref() {
+            return Ok(vec![Input {
+                source: Source::Inline,
+                contents: contents.clone(),
+            }]);
+        }
+
+        match self.filename.as_ref() {
+            Some(filename) if filename.as_os_str() != "-" => {
+                let contents = read_file(filename)?;
+                Ok(vec![Input {
+                    source: Source::File(filename.clone()),
+                    contents,
+                }])
+            }
+            _ => {
+                let mut contents = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(InputError::Stdin)?;
+                Ok(vec![Input {
+                    source: Source::Stdin,
+                    contents,
+                }])
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|error| InputError::File(path.to_owned(), error))
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<Input>, InputError> {
+    let mut paths = std::fs::read_dir(dir)
+        .map_err(|error| InputError::Dir(dir.to_owned(), error))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| InputError::Dir(dir.to_owned(), error))?;
+
+    paths.retain(|path| path.is_file());
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            debug!("Reading input {}", path.display());
+            let contents = read_file(&path)?;
+            Ok(Input {
+                source: Source::DirEntry(path),
+                contents,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("Cannot read standard input.")]
+    Stdin(#[source] std::io::Error),
+    #[error("Cannot read contents of file `{}`.", .0.display())]
+    File(PathBuf, #[source] std::io::Error),
+    #[error("Cannot list directory `{}`.", .0.display())]
+    Dir(PathBuf, #[source] std::io::Error),
+}
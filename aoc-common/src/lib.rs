@@ -0,0 +1,26 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+//! Bootstrap shared by every day: command line arguments, logging setup, input loading
+//! and the [`Solver`] interface together with [`run`] driving it for the `aoc` runner and every day.
+
+mod answers;
+mod args;
+mod diagnostics;
+mod error;
+mod input;
+mod logging;
+mod output;
+mod run;
+mod solver;
+
+pub use answers::{input_hash, Answers, AnswersError, Verifier};
+pub use args::CommonArgs;
+pub use diagnostics::{Diagnostics, Mode};
+pub use error::{ParseError, Span};
+pub use input::{Input, InputError, Source};
+pub use output::{csv_field, csv_row, json_line, Format, Record};
+pub use run::{report_dropped, run, solve_parts, Solution};
+pub use solver::{Answer, Part, Parts, Solver};
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,32 @@
+use tracing::Level;
+use tracing_subscriber::FmtSubscriber;
+
+use crate::CommonArgs;
+
+impl CommonArgs {
+    /// Maps the number of `-v` flags to a log level.
+    #[must_use]
+    pub fn log_level(&self) -> Level {
+        match self.verbose {
+            0 => Level::ERROR,
+            1 => Level::WARN,
+            2 => Level::INFO,
+            3 => Level::DEBUG,
+            _ => Level::TRACE,
+        }
+    }
+
+    /// Installs global `tracing` subscriber with level selected by [`CommonArgs::log_level`].
+    ///
+    /// Logs are written to stderr, so that they never mix with answers printed to stdout.
+    pub fn init_logging(&self) {
+        let subscriber = FmtSubscriber::builder()
+            .with_max_level(self.log_level())
+            .with_writer(std::io::stderr)
+            .finish();
+        if let Err(error) = tracing::subscriber::set_global_default(subscriber) {
+            eprintln!("[ERROR] Cannot set up logging.");
+            eprintln!("Error cause: {error}");
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::{borrow::Cow, fmt::Display};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{Answer, Input, Part, Solution, Source};
+
+/// Format in which answers are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Bare answers, one per line.
+    #[default]
+    Text,
+    /// One JSON object per answer and line.
+    Json,
+    /// Comma separated values with header line.
+    Csv,
+}
+
+/// Answer to a single part described with everything needed to store it outside of this program.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed_ms: f64,
+    pub input: String,
+}
+
+impl Record {
+    #[must_use]
+    pub fn new(day: u8, input: &Input, solution: &Solution) -> Self {
+        Self {
+            day,
+            part: solution.part,
+            answer: solution.answer,
+            elapsed_ms: solution.elapsed.as_secs_f64() * 1000.0,
+            input: input.name(),
+        }
+    }
+}
+
+impl Format {
+    /// Line printed once before any record.
+    #[must_use]
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Csv => Some("day,part,answer,elapsed_ms,input"),
+            Format::Text | Format::Json => None,
+        }
+    }
+
+    /// Formats single record as one line of output.
+    #[must_use]
+    pub fn format(self, record: &Record) -> String {
+        match self {
+            Format::Text => record.answer.to_string(),
+            Format::Json => json_line(record),
+            Format::Csv => csv_row(&[
+                &record.day,
+                &record.part,
+                &record.answer,
+                &format!("{:.3}", record.elapsed_ms),
+                &record.input,
+            ]),
+        }
+    }
+}
+
+impl Input {
+    /// Name identifying the input in structured output.
+    #[must_use]
+    pub fn name(&self) -> String {
+        match &self.source {
+            Source::Stdin => "-".to_owned(),
+            Source::Inline => "<inline>".to_owned(),
+            Source::File(path) | Source::DirEntry(path) => path.display().to_string(),
+        }
+    }
+}
+
+/// Serializes the value as a JSON object on a single line.
+///
+/// # Panics
+///
+/// Panics if the value fails to serialize, which only values with maps keyed by non-strings or
+/// failing [`Serialize`] implementations do.
+#[must_use]
+pub fn json_line(value: &impl Serialize) -> String {
+    serde_json::to_string(value).expect("value serializes to JSON")
+}
+
+/// Fields joined into a single line of CSV, see [`csv_field`].
+#[must_use]
+pub fn csv_row(fields: &[&dyn Display]) -> String {
+    fields
+        .iter()
+        .map(|field| csv_field(&field.to_string()).into_owned())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Field of CSV, quoted when it contains a separator, a quote or a line break.
+#[must_use]
+pub fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use tracing::error;
+
+use crate::{
+    Answer, CommonArgs, Diagnostics, Format, Input, ParseError, Part, Parts, Record, Solver,
+    Verifier,
+};
+
+/// Answer to a single part together with time spent computing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Solution {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Solves selected parts of the puzzle one after another, measuring wall-clock time of each.
+///
+/// Parts which are not selected are not computed at all. Every part is solved regardless of errors
+/// found in the input by the other one, so each result is either its solution or its first error.
+pub fn solve_parts(
+    solver: &dyn Solver,
+    parts: Parts,
+    input: &str,
+    diagnostics: &mut Diagnostics,
+) -> Vec<Result<Solution, ParseError>> {
+    parts
+        .to_vec()
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = solver.solve(part, input, diagnostics)?;
+            Ok(Solution {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect()
+}
+
+/// Prints warning summary of everything dropped from the input in lenient mode on standard error.
+pub fn report_dropped(input: &Input, diagnostics: &Diagnostics) {
+    let dropped = diagnostics.dropped();
+    if dropped.is_empty() {
+        return;
+    }
+
+    let name = input.name();
+    eprintln!("warning: dropped {} fragment(s) of {name}", dropped.len());
+    for error in dropped {
+        eprintln!("{}", error.render_warning(&name, &input.contents));
+    }
+}
+
+/// Solves every input selected on command line and prints answers of selected parts in selected format.
+///
+/// In text format time spent on each part is reported on standard error when verbose output was requested.
+/// With `--check` answers are compared with recorded ones and failure is returned on any mismatch,
+/// with `--record` they are stored in the answers file. Errors found in inputs are rendered on standard error
+/// in place of answers of parts which found them, and failure is returned as well.
+pub fn run(solver: &dyn Solver, args: &CommonArgs) -> ExitCode {
+    let inputs = match args.read_inputs() {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            error!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut verifier = match Verifier::from_args(args) {
+        Ok(verifier) => verifier,
+        Err(error) => {
+            error!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(header) = args.format.header() {
+        println!("{header}");
+    }
+
+    let mut failed = false;
+    for input in inputs {
+        if args.format == Format::Text {
+            if let Some(header) = input.header() {
+                println!("{header}");
+            }
+        }
+
+        let mut diagnostics = Diagnostics::new(args.mode());
+        let solutions = solve_parts(solver, args.part, input.contents.as_str(), &mut diagnostics);
+        report_dropped(&input, &diagnostics);
+
+        for solution in solutions {
+            let solution = match solution {
+                Ok(solution) => solution,
+                Err(error) => {
+                    eprintln!("{}", error.render(&input.name(), &input.contents));
+                    failed = true;
+                    continue;
+                }
+            };
+            let record = Record::new(solver.day(), &input, &solution);
+            println!("{}", args.format.format(&record));
+            if args.format == Format::Text && args.verbose > 0 {
+                eprintln!("Part {} took {:.2?}", solution.part, solution.elapsed);
+            }
+            if let Some(verifier) = verifier.as_mut() {
+                verifier.verify(solver.day(), &input, &solution);
+            }
+        }
+    }
+
+    match verifier.map_or(ExitCode::SUCCESS, Verifier::finish) {
+        _ if failed => ExitCode::FAILURE,
+        code => code,
+    }
+}
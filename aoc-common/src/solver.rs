@@ -0,0 +1,152 @@
+use std::fmt::{self, Display};
+
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+use crate::{Diagnostics, ParseError};
+
+/// Puzzle solution for a single day.
+///
+/// Every day implements it for a unit-like struct (e.g. `day_7::Day7`), so that the `aoc` runner
+/// can dispatch to any of them through a registry of trait objects. Malformed input is reported as
+/// [`ParseError`] pointing into the input, or dropped into [`Diagnostics`] depending on its mode.
+pub trait Solver: Sync {
+    /// Day of the Advent this solver belongs to.
+    fn day(&self) -> u8;
+
+    /// Solves part one of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Fails when input is malformed.
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError>;
+
+    /// Solves part two of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Fails when input is malformed.
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError>;
+
+    /// Solves selected part of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Fails when input is malformed.
+    fn solve(
+        &self,
+        part: Part,
+        input: &str,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => self.part_one(input, diagnostics),
+            Part::Two => self.part_two(input, diagnostics),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+/// Selection of parts to solve.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Parts {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    #[default]
+    Both,
+}
+
+impl Parts {
+    /// Selected parts in order they should be solved.
+    #[must_use]
+    pub fn to_vec(self) -> Vec<Part> {
+        match self {
+            Parts::One => vec![Part::One],
+            Parts::Two => vec![Part::Two],
+            Parts::Both => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+/// Answer to a single part of the puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Signed(value) => serializer.serialize_i64(*value),
+            Answer::Unsigned(value) => serializer.serialize_u64(*value),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => value.fmt(f),
+            Answer::Unsigned(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Signed(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        // usize is at most 64 bits wide on every supported platform.
+        Answer::Unsigned(value as u64)
+    }
+}
@@ -0,0 +1,101 @@
+use super::*;
+
+#[test]
+fn test_read_inline_input() {
+    let args = CommonArgs {
+        input_str: Some("1abc2".to_owned()),
+        ..CommonArgs::default()
+    };
+
+    let inputs = args.read_inputs().unwrap();
+
+    assert_eq!(
+        inputs,
+        vec![Input {
+            source: Source::Inline,
+            contents: "1abc2".to_owned()
+        }]
+    );
+    assert_eq!(inputs[0].header(), None);
+}
+
+#[test]
+fn test_read_dir_inputs() {
+    let dir = std::env::temp_dir().join(format!("aoc-common-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("nested")).unwrap();
+    std::fs::write(dir.join("b.txt"), "second").unwrap();
+    std::fs::write(dir.join("a.txt"), "first").unwrap();
+
+    let args = CommonArgs {
+        dir: Some(dir.clone()),
+        ..CommonArgs::default()
+    };
+    let inputs = args.read_inputs();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let contents = inputs
+        .unwrap()
+        .into_iter()
+        .map(|input| input.contents)
+        .collect::<Vec<_>>();
+
+    assert_eq!(contents, vec!["first", "second"]);
+}
+
+#[test]
+fn test_format_record() {
+    let record = Record {
+        day: 4,
+        part: Part::Two,
+        answer: Answer::Unsigned(30),
+        elapsed_ms: 1.5,
+        input: "inputs/day,4.txt".to_owned(),
+    };
+
+    assert_eq!(Format::Text.format(&record), "30");
+    assert_eq!(
+        Format::Json.format(&record),
+        r#"{"day":4,"part":2,"answer":30,"elapsed_ms":1.5,"input":"inputs/day,4.txt"}"#
+    );
+    assert_eq!(
+        Format::Csv.format(&record),
+        r#"4,2,30,1.500,"inputs/day,4.txt""#
+    );
+    assert_eq!(
+        csv_row(&[&"say \"hi\"", &'\n', &7]),
+        "\"say \"\"hi\"\"\",\"\n\",7"
+    );
+}
+
+#[test]
+fn test_answers_round_trip() {
+    let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+    let hash = input_hash("0 3 6 9 12 15");
+
+    let mut answers = Answers::load(&path).unwrap();
+    assert_eq!(answers, Answers::default());
+
+    answers.insert(9, Part::One, hash.clone(), Answer::Signed(18));
+    answers.save(&path).unwrap();
+    let loaded = Answers::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    let loaded = loaded.unwrap();
+
+    assert_eq!(hash, input_hash("0 3 6 9 12 15"));
+    assert_ne!(hash, input_hash("0 3 6 9 12 15\n"));
+    assert_eq!(loaded.get(9, Part::One, &hash), Some("18"));
+    assert_eq!(loaded.get(9, Part::Two, &hash), None);
+}
+
+#[test]
+fn test_render_parse_error() {
+    let input = "Game 1: 3 blue\nGame x: 4 red\n";
+    let line = input.lines().nth(1).unwrap();
+    let error = ParseError::at(input, &line[5..6], "invalid game id `x`");
+
+    assert_eq!(error.span.line_column(input), (2, 6));
+    assert_eq!(
+        error.render("input.txt", input),
+        "error: invalid game id `x`\n --> input.txt:2:6\n  |\n2 | Game x: 4 red\n  |      ^"
+    );
+}
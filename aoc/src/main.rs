@@ -0,0 +1,220 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc_common::{
+    report_dropped, solve_parts, CommonArgs, Diagnostics, Format, Input, Part, Record, Solution,
+    Source, Verifier,
+};
+use clap::{Parser, Subcommand};
+use tracing::{error, warn};
+
+mod registry;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(mut args) => {
+            args.common.init_logging();
+
+            if let Some(input) = args.input.take() {
+                args.common.filename = Some(input);
+            }
+
+            match args.day {
+                Some(day) if !args.all => run_day(day, &args),
+                _ => run_all(&args),
+            }
+        }
+    }
+}
+
+fn run_day(day: u8, args: &RunArgs) -> ExitCode {
+    let Some(solver) = registry::find(day) else {
+        error!("Day {day} is not registered.");
+        return ExitCode::FAILURE;
+    };
+
+    aoc_common::run(solver.as_ref(), &args.common)
+}
+
+fn run_all(args: &RunArgs) -> ExitCode {
+    let mut summary = Vec::new();
+    let mut failed = false;
+    let format = args.common.format;
+    let mut verifier = match Verifier::from_args(&args.common) {
+        Ok(verifier) => verifier,
+        Err(error) => {
+            error!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(header) = format.header() {
+        println!("{header}");
+    }
+
+    for solver in registry::solvers() {
+        let path = args
+            .root
+            .join(format!("day-{}", solver.day()))
+            .join("input.txt");
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            warn!(
+                "Skipping day {}: cannot read `{}`.",
+                solver.day(),
+                path.display()
+            );
+            summary.push((solver.day(), Vec::new()));
+            continue;
+        };
+        let input = Input {
+            source: Source::File(path),
+            contents,
+        };
+
+        let mut diagnostics = Diagnostics::new(args.common.mode());
+        let solutions = solve_parts(
+            solver.as_ref(),
+            args.common.part,
+            &input.contents,
+            &mut diagnostics,
+        );
+        report_dropped(&input, &diagnostics);
+        let solutions = solutions
+            .into_iter()
+            .filter_map(|solution| {
+                solution
+                    .map_err(|error| {
+                        eprintln!("{}", error.render(&input.name(), &input.contents));
+                        failed = true;
+                    })
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+        if let Some(verifier) = verifier.as_mut() {
+            for solution in &solutions {
+                verifier.verify(solver.day(), &input, solution);
+            }
+        }
+        if format != Format::Text {
+            for solution in &solutions {
+                println!(
+                    "{}",
+                    format.format(&Record::new(solver.day(), &input, solution))
+                );
+            }
+        }
+
+        summary.push((solver.day(), solutions));
+    }
+
+    if format == Format::Text {
+        print_summary(&summary);
+    }
+
+    match verifier.map_or(ExitCode::SUCCESS, Verifier::finish) {
+        _ if failed => ExitCode::FAILURE,
+        code => code,
+    }
+}
+
+fn print_summary(summary: &[(u8, Vec<Solution>)]) {
+    let cell = |solutions: &[Solution], part| {
+        solutions
+            .iter()
+            .find(|solution| solution.part == part)
+            .map_or_else(|| "-".to_owned(), |solution| solution.answer.to_string())
+    };
+    let time = |solutions: &[Solution]| {
+        if solutions.is_empty() {
+            "-".to_owned()
+        } else {
+            format!(
+                "{:.2?}",
+                solutions
+                    .iter()
+                    .map(|solution| solution.elapsed)
+                    .sum::<Duration>()
+            )
+        }
+    };
+
+    let rows = summary
+        .iter()
+        .map(|(day, solutions)| {
+            (
+                day,
+                cell(solutions, Part::One),
+                cell(solutions, Part::Two),
+                time(solutions),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let width = rows
+        .iter()
+        .flat_map(|(_, first, second, _)| [first.len(), second.len()])
+        .chain(["Part 1".len()])
+        .max()
+        .unwrap_or_default();
+    let time_width = rows
+        .iter()
+        .map(|(.., time)| time.len())
+        .chain(["Time".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:>3} | {:>width$} | {:>width$} | {:>time_width$}",
+        "Day", "Part 1", "Part 2", "Time"
+    );
+    println!(
+        "{:->3}-+-{:->width$}-+-{:->width$}-+-{:->time_width$}",
+        "", "", "", ""
+    );
+    for (day, first, second, time) in rows {
+        println!("{day:>3} | {first:>width$} | {second:>width$} | {time:>time_width$}");
+    }
+}
+
+/// Runs solutions of any registered day.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves puzzle of a single day or of every registered day.
+    Run(RunArgs),
+}
+
+#[derive(clap::Args)]
+struct RunArgs {
+    #[command(flatten)]
+    common: CommonArgs,
+    /// Day to solve.
+    #[arg(
+        short,
+        long,
+        value_name = "<DAY>",
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    day: Option<u8>,
+    /// Solves every registered day with input read from `<ROOT>/day-<N>/input.txt`.
+    #[arg(long, conflicts_with_all = ["filename", "input_str", "dir"])]
+    all: bool,
+    /// Directory containing `day-<N>` input directories used by `--all`.
+    #[arg(long, value_name = "<DIR>", default_value = ".")]
+    root: PathBuf,
+    /// Puzzle input file. Same as `--filename`.
+    #[arg(value_name = "<FILE>", conflicts_with_all = ["filename", "input_str", "dir", "all"])]
+    input: Option<PathBuf>,
+}
@@ -0,0 +1,20 @@
+use aoc_common::Solver;
+
+/// All days that can be run through `aoc run`, in order.
+pub fn solvers() -> Vec<Box<dyn Solver>> {
+    vec![
+        Box::new(day_1::Day1::default()),
+        Box::new(day_2::Day2::default()),
+        Box::new(day_3::Day3),
+        Box::new(day_4::Day4),
+        Box::new(day_5::Day5),
+        Box::new(day_6::Day6),
+        Box::new(day_7::Day7),
+        Box::new(day_8::Day8),
+        Box::new(day_9::Day9),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Box<dyn Solver>> {
+    solvers().into_iter().find(|solver| solver.day() == day)
+}
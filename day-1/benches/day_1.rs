@@ -0,0 +1,95 @@
+use aoc_common::Diagnostics;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_1::{
+    calibration_sum, corrected_calibration_sum, par_stream_calibration_sums,
+    stream_calibration_sums, Scanner,
+};
+
+const EXAMPLE: &str = include_str!("../test_2.txt");
+const DIGITS_EXAMPLE: &str = include_str!("../test.txt");
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Lines mixing digits and digit words, overlapping ones like `eightwo` included.
+fn synthetic(lines: usize) -> String {
+    (0..lines)
+        .map(|i| {
+            format!(
+                "{}x{}{}eightwo{}q{}\n",
+                WORDS[i % 9],
+                i % 10,
+                WORDS[(i * 7) % 9],
+                (i * 3) % 10,
+                WORDS[(i * 5) % 9]
+            )
+        })
+        .collect()
+}
+
+fn solvers(c: &mut Criterion) {
+    let inputs = [
+        ("example", EXAMPLE.to_owned()),
+        ("digits-example", DIGITS_EXAMPLE.to_owned()),
+        ("synthetic", synthetic(10_000)),
+    ];
+    let mut group = c.benchmark_group("day-1");
+
+    for (name, input) in &inputs {
+        group.bench_with_input(
+            BenchmarkId::new("calibration_sum", name),
+            input,
+            |b, input| {
+                b.iter(|| calibration_sum(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("corrected_calibration_sum", name),
+            input,
+            |b, input| {
+                b.iter(|| corrected_calibration_sum(black_box(input), &mut Diagnostics::default()))
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn streams(c: &mut Criterion) {
+    let input = synthetic(100_000);
+    let (digits, spelled) = (Scanner::digits(), Scanner::digits_and_words());
+    let mut group = c.benchmark_group("day-1/stream");
+
+    group.bench_with_input(
+        BenchmarkId::new("stream_calibration_sums", "synthetic"),
+        &input,
+        |b, input| {
+            b.iter(|| {
+                stream_calibration_sums(
+                    black_box(input.as_bytes()),
+                    &[&digits, &spelled],
+                    &mut Diagnostics::default(),
+                )
+            });
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("par_stream_calibration_sums", "synthetic"),
+        &input,
+        |b, input| {
+            b.iter(|| {
+                par_stream_calibration_sums(
+                    black_box(input.as_bytes()),
+                    &[&digits, &spelled],
+                    &mut Diagnostics::default(),
+                )
+            });
+        },
+    );
+
+    group.finish();
+}
+
+criterion_group!(benches, solvers, streams);
+criterion_main!(benches);
@@ -0,0 +1,99 @@
+use std::fmt::{self, Display};
+
+use crate::{calibration_value, decimal_value, Scanner, Token};
+
+/// Tokens picked from a single line in both parts and calibration values they make.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    /// 1-based line number.
+    pub line: usize,
+    pub text: &'a str,
+    /// First and last token picked in part one, if any.
+    pub digits: Option<(Token<'a>, Token<'a>)>,
+    /// First and last token picked in part two, if any.
+    pub spelled: Option<(Token<'a>, Token<'a>)>,
+}
+
+impl Explanation<'_> {
+    /// Calibration value of the line in part one.
+    #[must_use]
+    pub fn digits_value(&self) -> Option<u32> {
+        self.digits.map(calibration_value)
+    }
+
+    /// Calibration value of the line in part two.
+    #[must_use]
+    pub fn spelled_value(&self) -> Option<u32> {
+        self.spelled.map(calibration_value)
+    }
+
+    /// Whether the line is worth something else in part one than in part two.
+    #[must_use]
+    pub fn differs(&self) -> bool {
+        self.digits_value() != self.spelled_value()
+    }
+
+    fn fmt_part(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        part: u8,
+        tokens: Option<(Token, Token)>,
+    ) -> fmt::Result {
+        let Some((first, last)) = tokens else {
+            return writeln!(f, "  part {part}: no token");
+        };
+
+        let token = |token: Token| {
+            let text = &self.text[token.start..token.end];
+            let kind = if text.chars().all(|c| decimal_value(c).is_some()) {
+                "digit"
+            } else {
+                "word"
+            };
+            format!("`{text}` ({kind}) at byte {}", token.start)
+        };
+        writeln!(
+            f,
+            "  part {part}: first {}, last {}, value {}",
+            token(first),
+            token(last),
+            calibration_value((first, last))
+        )
+    }
+}
+
+impl Display for Explanation<'_> {
+    /// Renders the explanation as a block of three lines:
+    ///
+    /// ```text
+    /// line 3 `abcone2threexyz`
+    ///   part 1: first `2` (digit) at byte 6, last `2` (digit) at byte 6, value 22
+    ///   part 2: first `one` (word) at byte 3, last `three` (word) at byte 7, value 13
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "line {} `{}`", self.line, self.text)?;
+        self.fmt_part(f, 1, self.digits)?;
+        self.fmt_part(f, 2, self.spelled)
+    }
+}
+
+/// Explains every non-blank line of the document, picking tokens of part one with `digits` scanner
+/// and tokens of part two with `spelled` one.
+#[must_use]
+pub fn explain<'a>(
+    contents: &'a str,
+    digits: &'a Scanner,
+    spelled: &'a Scanner,
+) -> Vec<Explanation<'a>> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(index, text)| Explanation {
+            line: index + 1,
+            text,
+            digits: digits.first_last(text),
+            spelled: spelled.first_last(text),
+        })
+        .collect()
+}
@@ -0,0 +1,168 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::unicode;
+
+/// Digits spelled out with English words, the lexicon used unless another one is given.
+pub const DIGIT_WORDS: [(&str, &str); 9] = [
+    ("one", "1"),
+    ("two", "2"),
+    ("three", "3"),
+    ("four", "4"),
+    ("five", "5"),
+    ("six", "6"),
+    ("seven", "7"),
+    ("eight", "8"),
+    ("nine", "9"),
+];
+
+/// Tokens spelling out digits, every one mapped to the string of digits it stands for.
+///
+/// Lexicon file maps tokens to digits at its top level and replaces English words entirely:
+///
+/// ```toml
+/// zero = "0"
+/// ten = "10"
+/// twelve = "12"
+/// ```
+///
+/// First token of a line contributes the first of its digits, last token the last one.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Lexicon(BTreeMap<String, String>);
+
+impl Default for Lexicon {
+    fn default() -> Self {
+        Self(
+            DIGIT_WORDS
+                .iter()
+                .map(|&(token, digits)| (token.to_owned(), digits.to_owned()))
+                .collect(),
+        )
+    }
+}
+
+impl Lexicon {
+    /// Loads and validates lexicon from given file.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the file cannot be read, is not a valid lexicon file or the lexicon is invalid.
+    pub fn load(path: &Path) -> Result<Self, LexiconError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| LexiconError::Read(path.to_owned(), error))?;
+        let lexicon: Self = toml::from_str(&contents)
+            .map_err(|error| LexiconError::Parse(path.to_owned(), error))?;
+
+        lexicon.validate()?;
+        Ok(lexicon)
+    }
+
+    /// Checks that every token is made of non-digits (in any script) and stands for ASCII digits
+    /// only, and that no token lies within another one other than as its prefix. Such tokens would
+    /// make the first or the last token of a line ambiguous (e.g. `one` within `xoney`), while
+    /// tokens sharing a prefix are resolved in favour of the longer one.
+    ///
+    /// # Errors
+    ///
+    /// Fails on the first invalid token.
+    pub fn validate(&self) -> Result<(), LexiconError> {
+        for (token, digits) in &self.0 {
+            if token.is_empty() {
+                return Err(LexiconError::EmptyToken);
+            }
+            if token.chars().any(|c| unicode::decimal_value(c).is_some()) {
+                return Err(LexiconError::DigitInToken(token.clone()));
+            }
+            if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(LexiconError::InvalidDigits(token.clone(), digits.clone()));
+            }
+        }
+
+        for token in self.0.keys() {
+            let overlapped = self.0.keys().find(|other| {
+                other.len() > token.len()
+                    && other
+                        .match_indices(token.as_str())
+                        .any(|(offset, _)| offset > 0)
+            });
+            if let Some(other) = overlapped {
+                return Err(LexiconError::Overlapping(token.clone(), other.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lexicon without any token.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    /// Lexicon with every token folded for matching in any case and normalisation form, see
+    /// [`fold`](crate::fold).
+    ///
+    /// # Errors
+    ///
+    /// Fails when tokens folding to the same string stand for different digits, or when the folded
+    /// lexicon is invalid.
+    pub fn folded(&self) -> Result<Self, LexiconError> {
+        let mut folded = BTreeMap::<String, (&String, &String)>::new();
+
+        for (token, digits) in &self.0 {
+            let key = unicode::fold(token);
+            match folded.get(&key) {
+                Some((other, other_digits)) if *other_digits != digits => {
+                    return Err(LexiconError::Ambiguous((*other).clone(), token.clone()));
+                }
+                Some(_) => {}
+                None => {
+                    folded.insert(key, (token, digits));
+                }
+            }
+        }
+
+        let folded = Self(
+            folded
+                .into_iter()
+                .map(|(key, (_, digits))| (key, digits.clone()))
+                .collect(),
+        );
+        folded.validate()?;
+        Ok(folded)
+    }
+
+    /// Tokens together with digits they stand for.
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(token, digits)| (token.as_str(), digits.as_str()))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum LexiconError {
+    #[error("Cannot read lexicon file `{0}`: {1}")]
+    Read(PathBuf, #[source] io::Error),
+    #[error("Cannot parse lexicon file `{0}`: {1}")]
+    Parse(PathBuf, #[source] toml::de::Error),
+    #[error("Lexicon has an empty token")]
+    EmptyToken,
+    #[error("Lexicon token `{0}` contains a digit")]
+    DigitInToken(String),
+    #[error("Lexicon token `{0}` stands for `{1}`, which is not a string of digits")]
+    InvalidDigits(String, String),
+    #[error("Lexicon token `{0}` lies within token `{1}`, which makes them ambiguous")]
+    Overlapping(String, String),
+    #[error("Lexicon tokens `{0}` and `{1}` are the same in Unicode mode, but stand for different digits")]
+    Ambiguous(String, String),
+    #[error("Cannot build scanner of lexicon tokens: {0}")]
+    Build(#[from] aho_corasick::BuildError),
+}
@@ -0,0 +1,124 @@
+#![warn(clippy::pedantic)]
+#![warn(clippy::all)]
+//! Day 1: Trebuchet?!
+//!
+//! Calibration value of a line is made of its first and last digit. In part two digits may also be
+//! spelled out with letters, as defined by a [`Lexicon`].
+
+mod explain;
+mod lexicon;
+mod scanner;
+mod stream;
+mod unicode;
+
+use aoc_common::{Answer, Diagnostics, ParseError, Solver};
+use tracing::trace;
+
+pub use explain::{explain, Explanation};
+pub use lexicon::{Lexicon, LexiconError, DIGIT_WORDS};
+pub use scanner::{Scanner, Token, DIGITS};
+pub use stream::{
+    par_stream_calibration_sums, stream_calibration_sums, StreamError, StreamSums, CHUNK_SIZE,
+};
+pub use unicode::{decimal_value, fold};
+
+/// Solver of day 1, picking tokens of part one with `digits` scanner and of part two with `spelled`
+/// one.
+#[derive(Clone, Debug)]
+pub struct Day1 {
+    pub digits: Scanner,
+    pub spelled: Scanner,
+}
+
+impl Default for Day1 {
+    /// Solver reading ASCII digits and digits spelled out with English words.
+    fn default() -> Self {
+        Self {
+            digits: Scanner::digits(),
+            spelled: Scanner::digits_and_words(),
+        }
+    }
+}
+
+impl Solver for Day1 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        sum_calibration_values(input, diagnostics, &self.digits).map(Into::into)
+    }
+
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        sum_calibration_values(input, diagnostics, &self.spelled).map(Into::into)
+    }
+}
+
+/// Sums calibration values made of ASCII digits only.
+///
+/// # Errors
+///
+/// Fails on the first line without any digit, unless in lenient mode which drops such lines.
+pub fn calibration_sum(contents: &str, diagnostics: &mut Diagnostics) -> Result<u64, ParseError> {
+    sum_calibration_values(contents, diagnostics, &Scanner::digits())
+}
+
+/// Sums calibration values taking digits spelled out with letters (`one` ... `nine`) into account.
+///
+/// # Errors
+///
+/// Fails on the first line without any digit, spelled out or not, unless in lenient mode which drops
+/// such lines.
+pub fn corrected_calibration_sum(
+    contents: &str,
+    diagnostics: &mut Diagnostics,
+) -> Result<u64, ParseError> {
+    sum_calibration_values(contents, diagnostics, &Scanner::digits_and_words())
+}
+
+/// Sums calibration values made of the first and the last token found by the scanner.
+///
+/// # Errors
+///
+/// Fails on the first line without any token, unless in lenient mode which drops such lines.
+pub fn sum_calibration_values(
+    contents: &str,
+    diagnostics: &mut Diagnostics,
+    scanner: &Scanner,
+) -> Result<u64, ParseError> {
+    let mut sum = 0;
+
+    for line in contents.lines() {
+        if line.is_empty() {
+            diagnostics.ignore(ParseError::at(contents, line, "unexpected blank line"))?;
+            continue;
+        }
+
+        let line_value = line_value(scanner, line).ok_or_else(|| {
+            ParseError::at(
+                contents,
+                line,
+                "line has no digit to make calibration value of",
+            )
+        });
+        sum += u64::from(diagnostics.record(line_value)?.unwrap_or_default());
+    }
+
+    Ok(sum)
+}
+
+/// Calibration value made of the first and the last token of the line.
+fn line_value(scanner: &Scanner, line: &str) -> Option<u32> {
+    let (first, last) = scanner.first_last(line)?;
+    trace!("Line {line:?}: first {first:?}, last {last:?}");
+
+    Some(calibration_value((first, last)))
+}
+
+/// Calibration value made of the first digit of the first token and the last digit of the last one.
+fn calibration_value((first, last): (Token, Token)) -> u32 {
+    first.first_digit() * 10 + last.last_digit()
+}
+
+#[cfg(test)]
+mod test;
@@ -1,147 +1,172 @@
 #![warn(clippy::pedantic)]
 #![warn(clippy::all)]
 
-use std::path::PathBuf;
-
-use clap::{ArgAction, Parser};
-use tracing::{debug, error, info, trace, Level};
-use tracing_subscriber::FmtSubscriber;
-
-fn main() {
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+    process::ExitCode,
+    time::Instant,
+};
+
+use aoc_common::{CommonArgs, Diagnostics, Part, Record};
+use clap::Parser;
+use day_1::{
+    par_stream_calibration_sums, stream_calibration_sums, Day1, Lexicon, LexiconError, Scanner,
+    StreamSums,
+};
+use tracing::error;
+
+fn main() -> ExitCode {
     let args = Args::parse();
-
-    let log_level = match args.verbose {
-        0 => Level::ERROR,
-        1 => Level::WARN,
-        2 => Level::INFO,
-        3 => Level::DEBUG,
-        _ => Level::TRACE,
+    args.common.init_logging();
+
+    let solver = match solver(&args) {
+        Ok(solver) => solver,
+        Err(error) => {
+            error!("{error}");
+            return ExitCode::FAILURE;
+        }
     };
 
-    let subscriber = FmtSubscriber::builder().with_max_level(log_level).finish();
-    if let Err(error) = tracing::subscriber::set_global_default(subscriber) {
-        eprintln!("[ERROR] Cannot set up logging.");
-        eprintln!("Error cause: {error}");
+    if args.explain {
+        return explain(&args, &solver);
+    }
+    if args.stream {
+        return stream(&args, &solver);
+    }
+
+    aoc_common::run(&solver, &args.common)
+}
+
+/// Solver with scanners of the lexicon and mode given on command line.
+fn solver(args: &Args) -> Result<Day1, LexiconError> {
+    let lexicon = match &args.lexicon {
+        Some(path) => Lexicon::load(path)?,
+        None => Lexicon::default(),
     };
 
-    if args.filename.is_none() {
-        error!("Inputs other than file are currently not supported.");
-        return;
+    if args.unicode {
+        Ok(Day1 {
+            digits: Scanner::unicode_digits(),
+            spelled: Scanner::unicode_with_lexicon(&lexicon)?,
+        })
+    } else {
+        Ok(Day1 {
+            digits: Scanner::digits(),
+            spelled: Scanner::with_lexicon(&lexicon)?,
+        })
     }
+}
 
-    let Ok(contents) = std::fs::read_to_string(args.filename.unwrap()) else {
-        error!("Cannot read file contents.");
-        return;
+/// Prints tokens picked from every line of every input instead of solving the puzzle.
+fn explain(args: &Args, solver: &Day1) -> ExitCode {
+    let inputs = match args.common.read_inputs() {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            error!("{error}");
+            return ExitCode::FAILURE;
+        }
     };
+    for input in inputs {
+        if let Some(header) = input.header() {
+            println!("{header}");
+        }
+
+        day_1::explain(&input.contents, &solver.digits, &solver.spelled)
+            .into_iter()
+            .filter(|explanation| !args.differing || explanation.differs())
+            .for_each(|explanation| print!("{explanation}"));
+    }
 
-    let replacements = vec![
-        ("one", "1"),
-        ("two", "2"),
-        ("three", "3"),
-        ("four", "4"),
-        ("five", "5"),
-        ("six", "6"),
-        ("seven", "7"),
-        ("eight", "8"),
-        ("nine", "9"),
-    ];
-
-    let sum = contents
-        .lines()
-        .map(|line| {
-            debug!("Original string: {line}");
-            let mut line = line.to_string();
-            // This is one way to do it
-            let mut first = replacements
-                .iter()
-                .filter_map(|(value, replacement)| {
-                    if let Some(offset) = line.find(value) {
-                        Some((offset, value, replacement))
-                    } else {
-                        None
-                    }
-                })
-                .min_by_key(|&(offset, ..)| offset)
-                .map(|(offset, value, replacement)| {
-                    // Unfortunately this has to be done this way, because digit-words can overlap.
-                    ((offset + 1)..(offset + value.len() - 1), replacement)
-                });
-
-            let last = replacements
-                .iter()
-                .filter_map(|(value, replacement)| {
-                    if let Some(offset) = line.rfind(value) {
-                        Some((offset, value, replacement))
-                    } else {
-                        None
-                    }
-                })
-                .max_by_key(|&(offset, ..)| offset)
-                .map(|(offset, value, replacement)| {
-                    // See comment up.
-                    ((offset + 1)..(offset + value.len() - 1), replacement)
-                });
-
-            // Special case: replacements overlap. If that's the case we replace only first element.
-            if let (Some((first_range, ..)), Some((last_range, ..))) = (&first, &last) {
-                if first_range.end > last_range.start {
-                    first = None;
-                }
-            }
-
-            // We replace last occurence at first, because it will not change offsets.
-            // If first occurence was replaced at first, this could change offsets and invoke panic.
-            if let Some((range, replacement)) = last {
-                line.replace_range(range, &replacement);
-            }
+    ExitCode::SUCCESS
+}
 
-            if let Some((range, replacement)) = first {
-                line.replace_range(range, &replacement);
+/// Solves selected parts in a single pass over input file or standard input, without loading it
+/// into memory.
+fn stream(args: &Args, solver: &Day1) -> ExitCode {
+    let (name, reader): (String, Box<dyn BufRead>) = match &args.common.filename {
+        Some(path) if path.as_os_str() != "-" => match File::open(path) {
+            Ok(file) => (path.display().to_string(), Box::new(BufReader::new(file))),
+            Err(error) => {
+                error!("Cannot read input file `{}`: {error}", path.display());
+                return ExitCode::FAILURE;
             }
+        },
+        _ => ("-".to_owned(), Box::new(io::stdin().lock())),
+    };
 
-            // Alternatively define replacements as below and do full replacement:
-            // let replacements = vec![
-            //     ("one", "o1e"),
-            //     ("two", "t2o"),
-            //     ("three", "t3e"),
-            //     ("four", "f4r"),
-            //     ("five", "f5e"),
-            //     ("six", "s6x"),
-            //     ("seven", "s7n"),
-            //     ("eight", "e8t"),
-            //     ("nine", "n9e"),
-            // ];
-            // replacements.iter().for_each(|(value, replacement)| line = line.replace(value, &replacement));
-            debug!("Replaced string: {line}");
-            line
-        })
-        .map(|input| {
-            input
-                .chars()
-                .filter(char::is_ascii_digit)
-                .collect::<Vec<_>>()
-        })
-        .filter_map(|digits| {
-            trace!("Numbers in line: {digits:?}");
-            match (digits.first().copied(), digits.last().copied()) {
-                (Some(first), Some(last)) => Some(format!("{first}{last}")),
-                _ => None,
-            }
-        })
-        .filter_map(|value| {
-            info!("Line value: {value}");
-            value.parse::<u32>().ok()
+    let parts = args.common.part.to_vec();
+    let scanners = parts
+        .iter()
+        .map(|part| match part {
+            Part::One => &solver.digits,
+            Part::Two => &solver.spelled,
         })
-        .reduce(|u, v| u + v)
-        .unwrap_or(0);
+        .collect::<Vec<_>>();
+
+    let mut diagnostics = Diagnostics::new(args.common.mode());
+    let start = Instant::now();
+    let sums = if args.parallel {
+        par_stream_calibration_sums(reader, &scanners, &mut diagnostics)
+    } else {
+        stream_calibration_sums(reader, &scanners, &mut diagnostics)
+    };
+    let elapsed = start.elapsed();
+
+    let StreamSums { sums, dropped } = match sums {
+        Ok(sums) => sums,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if !dropped.is_empty() {
+        eprintln!("warning: dropped {} line(s) of {name}", dropped.len());
+        for (line, error) in &dropped {
+            eprintln!("  --> {name}:{line}: {error}");
+        }
+    }
+
+    if let Some(header) = args.common.format.header() {
+        println!("{header}");
+    }
+    for (part, sum) in parts.into_iter().zip(sums) {
+        let record = Record {
+            day: 1,
+            part,
+            answer: sum.into(),
+            elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+            input: name.clone(),
+        };
+        println!("{}", args.common.format.format(&record));
+    }
 
-    println!("{sum}");
+    ExitCode::SUCCESS
 }
 
 #[derive(Parser)]
+#[allow(clippy::struct_excessive_bools)]
 struct Args {
+    #[command(flatten)]
+    common: CommonArgs,
+    /// TOML file mapping tokens spelling out digits in part two to the digits, e.g. `ten = "10"`.
     #[arg(short, long, value_name = "<FILE>")]
-    filename: Option<PathBuf>,
-    #[arg(short, long, action = ArgAction::Count)]
-    verbose: u8,
+    lexicon: Option<PathBuf>,
+    /// Recognise decimal digits of any script and lexicon tokens in any case and normalisation form.
+    #[arg(short, long)]
+    unicode: bool,
+    /// Instead of solving, report first and last token picked from every line in both parts.
+    #[arg(long)]
+    explain: bool,
+    /// Explain only lines worth something else in part one than in part two.
+    #[arg(long, requires = "explain")]
+    differing: bool,
+    /// Solve selected parts in a single pass over the input file or standard input, keeping memory
+    /// use bounded regardless of its size.
+    #[arg(long, conflicts_with_all = ["explain", "input_str", "dir", "check", "record"])]
+    stream: bool,
+    /// Scan chunks of the streamed input in parallel.
+    #[arg(long, requires = "stream")]
+    parallel: bool,
 }
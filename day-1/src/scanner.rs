@@ -0,0 +1,196 @@
+use std::cmp::{self, Reverse};
+
+use aho_corasick::AhoCorasick;
+
+use crate::{unicode, Lexicon, LexiconError};
+
+/// ASCII digits, every one standing for itself.
+pub const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+/// Token of a line standing for a string of digits, located by byte offsets into the line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub start: usize,
+    pub end: usize,
+    pub digits: &'a str,
+}
+
+impl Token<'_> {
+    /// Digit the token contributes when it's the first token of a line.
+    #[must_use]
+    pub fn first_digit(&self) -> u32 {
+        self.digits
+            .chars()
+            .next()
+            .and_then(|digit| digit.to_digit(10))
+            .unwrap_or_default()
+    }
+
+    /// Digit the token contributes when it's the last token of a line.
+    #[must_use]
+    pub fn last_digit(&self) -> u32 {
+        self.digits
+            .chars()
+            .last()
+            .and_then(|digit| digit.to_digit(10))
+            .unwrap_or_default()
+    }
+}
+
+/// Finds the first and the last token of a line in a single pass over it.
+///
+/// Tokens are matched with an Aho–Corasick automaton reporting overlapping matches, so `eightwo`
+/// yields both `eight` and `two` without rewriting the line. Of tokens starting at the same offset
+/// (e.g. `eight` and `eighteen`) the longer one wins.
+///
+/// In Unicode mode any decimal digit (e.g. `７` or `٧`) is a token and tokens match regardless of
+/// case and normalisation form, see [`unicode::fold`]. Offsets of tokens still point into the line.
+#[derive(Clone, Debug)]
+pub struct Scanner {
+    automaton: AhoCorasick,
+    digits: Vec<String>,
+    unicode: bool,
+}
+
+impl Scanner {
+    /// Scanner of given tokens, every one of them paired with string of digits it stands for.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the automaton cannot be built, e.g. because tokens are too large.
+    pub fn new<'a>(
+        tokens: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, aho_corasick::BuildError> {
+        let (patterns, digits): (Vec<_>, Vec<_>) = tokens
+            .into_iter()
+            .map(|(token, digits)| (token, digits.to_owned()))
+            .unzip();
+
+        Ok(Self {
+            automaton: AhoCorasick::new(patterns)?,
+            digits,
+            unicode: false,
+        })
+    }
+
+    /// Scanner of ASCII digits only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if automaton of hard-coded digits cannot be built.
+    #[must_use]
+    pub fn digits() -> Self {
+        Self::new(DIGITS.into_iter().zip(DIGITS)).expect("Invalid digits")
+    }
+
+    /// Scanner of ASCII digits and tokens of the lexicon.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the automaton cannot be built, e.g. because tokens are too large.
+    pub fn with_lexicon(lexicon: &Lexicon) -> Result<Self, aho_corasick::BuildError> {
+        Self::new(DIGITS.into_iter().zip(DIGITS).chain(lexicon.tokens()))
+    }
+
+    /// Scanner of ASCII digits and digits spelled out with English words (`one` ... `nine`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if automaton of hard-coded digits and digit words cannot be built.
+    #[must_use]
+    pub fn digits_and_words() -> Self {
+        Self::with_lexicon(&Lexicon::default()).expect("Invalid digit words")
+    }
+
+    /// Scanner of Unicode decimal digits only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if automaton without any token cannot be built.
+    #[must_use]
+    pub fn unicode_digits() -> Self {
+        Self::unicode_with_lexicon(&Lexicon::empty()).expect("Invalid empty lexicon")
+    }
+
+    /// Scanner of Unicode decimal digits and tokens of the lexicon in any case and normalisation
+    /// form.
+    ///
+    /// # Errors
+    ///
+    /// Fails when tokens of the lexicon become ambiguous once folded or the automaton cannot be built.
+    pub fn unicode_with_lexicon(lexicon: &Lexicon) -> Result<Self, LexiconError> {
+        // Digits are recognised while folding the line, so the automaton looks for words only.
+        let lexicon = lexicon.folded()?;
+
+        Ok(Self {
+            unicode: true,
+            ..Self::new(lexicon.tokens())?
+        })
+    }
+
+    /// First and last token of the line, which are the same one when the line has a single token.
+    #[must_use]
+    pub fn first_last(&self, line: &str) -> Option<(Token<'_>, Token<'_>)> {
+        if self.unicode {
+            return self.unicode_first_last(line);
+        }
+
+        bounds(
+            self.automaton
+                .find_overlapping_iter(line)
+                .map(|found| Token {
+                    start: found.start(),
+                    end: found.end(),
+                    digits: &self.digits[found.pattern().as_usize()],
+                }),
+        )
+    }
+
+    fn unicode_first_last(&self, line: &str) -> Option<(Token<'_>, Token<'_>)> {
+        let mut folded = String::with_capacity(line.len());
+        // Byte range of the line character every byte of folded line comes from.
+        let mut origins = Vec::with_capacity(line.len());
+        let mut digits = Vec::new();
+
+        for (start, c) in line.char_indices() {
+            let end = start + c.len_utf8();
+            if let Some(value) = unicode::decimal_value(c) {
+                digits.push(Token {
+                    start,
+                    end,
+                    digits: DIGITS[value],
+                });
+                // Digit is kept as it is, so that it separates words around it.
+                folded.push(c);
+                origins.resize(folded.len(), (start, end));
+                continue;
+            }
+
+            folded.extend(unicode::fold_char(c));
+            origins.resize(folded.len(), (start, end));
+        }
+
+        let words = self
+            .automaton
+            .find_overlapping_iter(&folded)
+            .map(|found| Token {
+                start: origins[found.start()].0,
+                end: origins[found.end() - 1].1,
+                digits: &self.digits[found.pattern().as_usize()],
+            });
+        bounds(digits.into_iter().chain(words))
+    }
+}
+
+/// First and last of tokens coming in any order.
+fn bounds<'a>(tokens: impl Iterator<Item = Token<'a>>) -> Option<(Token<'a>, Token<'a>)> {
+    // Matches come ordered by their end, so a token nested in a longer one could come first.
+    // Comparing starts keeps the result right for any set of tokens.
+    tokens.fold(None, |bounds, token| match bounds {
+        None => Some((token, token)),
+        Some((first, last)) => Some((
+            cmp::min_by_key(first, token, |token| (token.start, Reverse(token.end))),
+            cmp::max_by_key(last, token, |token| (token.start, token.end)),
+        )),
+    })
+}
@@ -0,0 +1,186 @@
+use std::io::{self, BufRead, Read};
+
+use aoc_common::{Diagnostics, Mode, ParseError, Span};
+use rayon::prelude::*;
+use thiserror::Error;
+
+use crate::{line_value, Scanner};
+
+/// Bytes read at once by [`par_stream_calibration_sums`], extended to the end of the last line read.
+pub const CHUNK_SIZE: usize = 1 << 22;
+
+/// Sums of every scanner over lines of a stream.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StreamSums {
+    pub sums: Vec<u64>,
+    /// Lines dropped in lenient mode by their 1-based number, with the error which made them
+    /// dropped, as the stream itself is gone by the time they are reported.
+    pub dropped: Vec<(usize, ParseError)>,
+}
+
+/// Sums calibration values of every scanner in a single pass over lines of the reader.
+///
+/// Lines are read one by one into a reused buffer, so memory use is bounded by the longest line.
+/// Spans of errors are byte offsets into the whole stream.
+///
+/// # Errors
+///
+/// Fails when the reader fails or isn't valid UTF-8, or on the first line without any token of some
+/// scanner, unless in lenient mode which drops such lines from sums of such scanners.
+pub fn stream_calibration_sums(
+    mut reader: impl BufRead,
+    scanners: &[&Scanner],
+    diagnostics: &mut Diagnostics,
+) -> Result<StreamSums, StreamError> {
+    let mut totals = Totals::new(scanners.len());
+    let mut line = String::new();
+
+    while reader.read_line(&mut line)? > 0 {
+        let text = trim_newline(&line);
+        let values = scanners.iter().map(|scanner| line_value(scanner, text));
+        totals.add(&line, values, diagnostics)?;
+        line.clear();
+    }
+
+    Ok(totals.into_sums())
+}
+
+/// Sums calibration values of every scanner like [`stream_calibration_sums`], but reads the stream in
+/// chunks of about [`CHUNK_SIZE`] bytes and scans lines of each chunk in parallel.
+///
+/// # Errors
+///
+/// Fails when the reader fails or isn't valid UTF-8, or on the first line without any token of some
+/// scanner, unless in lenient mode which drops such lines from sums of such scanners.
+pub fn par_stream_calibration_sums(
+    mut reader: impl BufRead,
+    scanners: &[&Scanner],
+    diagnostics: &mut Diagnostics,
+) -> Result<StreamSums, StreamError> {
+    let mut totals = Totals::new(scanners.len());
+    let mut chunk = Vec::with_capacity(CHUNK_SIZE);
+
+    loop {
+        chunk.clear();
+        (&mut reader)
+            .take(CHUNK_SIZE as u64)
+            .read_to_end(&mut chunk)?;
+        reader.read_until(b'\n', &mut chunk)?;
+        if chunk.is_empty() {
+            break;
+        }
+
+        let chunk = std::str::from_utf8(&chunk)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        let lines = chunk.split_inclusive('\n').collect::<Vec<_>>();
+        // Values of all scanners for every line, flattened into a single allocation per chunk.
+        let values = lines
+            .par_iter()
+            .flat_map_iter(|line| {
+                scanners
+                    .iter()
+                    .map(|scanner| line_value(scanner, trim_newline(line)))
+            })
+            .collect::<Vec<_>>();
+
+        for (index, line) in lines.into_iter().enumerate() {
+            let values = &values[index * scanners.len()..(index + 1) * scanners.len()];
+            totals.add(line, values.iter().copied(), diagnostics)?;
+        }
+    }
+
+    Ok(totals.into_sums())
+}
+
+/// Line without its `\n` or `\r\n` terminator, like lines of [`str::lines`].
+fn trim_newline(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Sums of every scanner together with position in the stream.
+struct Totals {
+    sums: Vec<u64>,
+    dropped: Vec<(usize, ParseError)>,
+    /// Bytes of the stream consumed so far.
+    offset: usize,
+    /// Lines of the stream consumed so far.
+    line: usize,
+}
+
+impl Totals {
+    fn new(scanners: usize) -> Self {
+        Self {
+            sums: vec![0; scanners],
+            dropped: Vec::new(),
+            offset: 0,
+            line: 0,
+        }
+    }
+
+    /// Adds values of the line (terminator included) to the sums.
+    fn add(
+        &mut self,
+        line: &str,
+        values: impl Iterator<Item = Option<u32>>,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<(), StreamError> {
+        let text = trim_newline(line);
+        let span = Span::new(self.offset, self.offset + text.len());
+        self.offset += line.len();
+        self.line += 1;
+
+        let at_line = |error| StreamError::Parse {
+            line: self.line,
+            error,
+        };
+        if text.is_empty() {
+            let error = ParseError::new(span, "unexpected blank line");
+            diagnostics.ignore(error.clone()).map_err(at_line)?;
+            if diagnostics.mode == Mode::Lenient {
+                self.dropped.push((self.line, error));
+            }
+            return Ok(());
+        }
+
+        for (sum, value) in self.sums.iter_mut().zip(values) {
+            let value = value.ok_or_else(|| {
+                ParseError::new(span, "line has no digit to make calibration value of")
+            });
+            if let Err(error) = &value {
+                // Lines dropped by several scanners are listed once.
+                let dropped = (self.line, error.clone());
+                if self.dropped.last() != Some(&dropped) {
+                    self.dropped.push(dropped);
+                }
+            }
+            *sum += u64::from(
+                diagnostics
+                    .record(value)
+                    .map_err(at_line)?
+                    .unwrap_or_default(),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn into_sums(self) -> StreamSums {
+        StreamSums {
+            sums: self.sums,
+            dropped: self.dropped,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum StreamError {
+    #[error("Cannot read calibration document: {0}")]
+    Read(#[from] io::Error),
+    #[error("line {line}: {error}")]
+    Parse {
+        line: usize,
+        #[source]
+        error: ParseError,
+    },
+}
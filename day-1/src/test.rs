@@ -0,0 +1,165 @@
+use aoc_common::Mode;
+
+use super::*;
+
+#[test]
+fn test_calibration_sum() {
+    let contents = include_str!("../test.txt");
+    let sum = calibration_sum(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(142, sum);
+
+    // Spelled out digits are not digits in part one.
+    let contents = include_str!("../test_2.txt");
+    let error = calibration_sum(contents, &mut Diagnostics::default()).unwrap_err();
+
+    assert_eq!(&contents[error.span.start..error.span.end], "eightwothree");
+}
+
+#[test]
+fn test_corrected_calibration_sum() {
+    let contents = include_str!("../test.txt");
+    let sum = corrected_calibration_sum(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(142, sum);
+
+    let contents = include_str!("../test_2.txt");
+    let sum = corrected_calibration_sum(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(281, sum);
+}
+
+#[test]
+fn test_overlapping_digit_words() {
+    let scanner = Scanner::digits_and_words();
+    let (first, last) = scanner.first_last("xeightwo").unwrap();
+
+    assert_eq!((first.start, first.digits), (1, "8"));
+    assert_eq!((last.start, last.digits), (5, "2"));
+    assert_eq!(line_value(&scanner, "oneight"), Some(18));
+    assert_eq!(line_value(&scanner, "7"), Some(77));
+    assert_eq!(line_value(&scanner, "abc"), None);
+}
+
+#[test]
+fn test_lexicon() {
+    let lexicon: Lexicon = toml::from_str(
+        "zero = \"0\"\nten = \"10\"\ntwelve = \"12\"\neight = \"8\"\neighteen = \"18\"",
+    )
+    .unwrap();
+    lexicon.validate().unwrap();
+    let scanner = Scanner::with_lexicon(&lexicon).unwrap();
+
+    assert_eq!(line_value(&scanner, "twelvexzero"), Some(10));
+    assert_eq!(line_value(&scanner, "ten"), Some(10));
+    assert_eq!(line_value(&scanner, "eighteen"), Some(18));
+    assert_eq!(line_value(&scanner, "3eight"), Some(38));
+}
+
+#[test]
+fn test_invalid_lexicon() {
+    let validate = |contents: &str| toml::from_str::<Lexicon>(contents).unwrap().validate();
+
+    assert!(
+        matches!(validate("xoney = \"5\"\none = \"1\""), Err(LexiconError::Overlapping(token, other)) if token == "one" && other == "xoney")
+    );
+    assert!(matches!(
+        validate("ten = \"1O\""),
+        Err(LexiconError::InvalidDigits(..))
+    ));
+    assert!(matches!(
+        validate("4ty = \"40\""),
+        Err(LexiconError::DigitInToken(..))
+    ));
+}
+
+#[test]
+fn test_explain() {
+    let contents = include_str!("../test_2.txt");
+    let (digits, spelled) = (Scanner::digits(), Scanner::digits_and_words());
+    let explanations = explain(contents, &digits, &spelled);
+
+    assert_eq!(
+        explanations
+            .iter()
+            .filter(|explanation| explanation.differs())
+            .count(),
+        6
+    );
+    assert_eq!(
+        explanations[2].to_string(),
+        "line 3 `abcone2threexyz`\n  \
+         part 1: first `2` (digit) at byte 6, last `2` (digit) at byte 6, value 22\n  \
+         part 2: first `one` (word) at byte 3, last `three` (word) at byte 7, value 13\n"
+    );
+}
+
+#[test]
+fn test_stream_calibration_sums() {
+    let contents = include_str!("../test_2.txt");
+    let (digits, spelled) = (Scanner::digits(), Scanner::digits_and_words());
+
+    let mut diagnostics = Diagnostics::new(Mode::Lenient);
+    let sums = stream_calibration_sums(contents.as_bytes(), &[&digits, &spelled], &mut diagnostics)
+        .unwrap();
+    assert_eq!(sums.sums, [209, 281]);
+    assert_eq!(diagnostics.dropped().len(), 1);
+    assert_eq!(
+        sums.dropped
+            .iter()
+            .map(|(line, error)| (*line, &contents[error.span.start..error.span.end]))
+            .collect::<Vec<_>>(),
+        [(2, "eightwothree")]
+    );
+
+    let mut diagnostics = Diagnostics::new(Mode::Lenient);
+    let par_sums =
+        par_stream_calibration_sums(contents.as_bytes(), &[&digits, &spelled], &mut diagnostics);
+    assert_eq!(par_sums.unwrap(), sums);
+
+    let error =
+        stream_calibration_sums(contents.as_bytes(), &[&digits], &mut Diagnostics::default())
+            .unwrap_err();
+    assert!(
+        matches!(error, StreamError::Parse { line: 2, error } if &contents[error.span.start..error.span.end] == "eightwothree")
+    );
+}
+
+#[test]
+fn test_unicode_digits_and_words() {
+    let scanner = Scanner::unicode_with_lexicon(&Lexicon::default()).unwrap();
+
+    // Arabic-Indic three and full-width seven around ASCII digit.
+    assert_eq!(line_value(&scanner, "ab٣c5d７"), Some(37));
+    assert_eq!(line_value(&scanner, "x١٢"), Some(12));
+    assert_eq!(line_value(&scanner, "ONExx９"), Some(19));
+    // Full-width letters are folded into ASCII ones.
+    assert_eq!(line_value(&scanner, "ｅｉｇｈｔwo"), Some(82));
+    assert_eq!(
+        line_value(&Scanner::digits_and_words(), "ab٣c5d７"),
+        Some(55)
+    );
+
+    let digits = Scanner::unicode_digits();
+    let (first, last) = digits.first_last("٣abc").unwrap();
+    assert_eq!((first.start, first.end, last.digits), (0, 2, "3"));
+}
+
+#[test]
+fn test_unicode_lexicon() {
+    // Precomposed `é` in NFC and `e` followed by combining acute accent in NFD.
+    let lexicon: Lexicon = toml::from_str("\"tr\u{e9}s\" = \"3\"\nZWEI = \"2\"").unwrap();
+    let scanner = Scanner::unicode_with_lexicon(&lexicon).unwrap();
+
+    let line = "zweixtre\u{301}s";
+    let (first, last) = scanner.first_last(line).unwrap();
+    assert_eq!(&line[last.start..last.end], "tre\u{301}s");
+    assert_eq!(line_value(&scanner, line), Some(23));
+    assert_eq!(first.start, 0);
+
+    let lexicon: Lexicon = toml::from_str("one = \"1\"\nONE = \"2\"").unwrap();
+    assert!(matches!(
+        Scanner::unicode_with_lexicon(&lexicon),
+        Err(LexiconError::Ambiguous(..))
+    ));
+}
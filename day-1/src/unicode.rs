@@ -0,0 +1,102 @@
+use std::iter;
+
+use unicode_normalization::UnicodeNormalization;
+
+/// First code point of every block of ten Unicode decimal digits (general category `Nd`), in
+/// ascending order. Every `Nd` character lies in one of these blocks and is worth its offset in it.
+///
+/// Generated from Unicode 14.0 character database.
+const DECIMAL_ZEROS: [char; 66] = [
+    '\u{0030}',
+    '\u{0660}',
+    '\u{06F0}',
+    '\u{07C0}',
+    '\u{0966}',
+    '\u{09E6}',
+    '\u{0A66}',
+    '\u{0AE6}',
+    '\u{0B66}',
+    '\u{0BE6}',
+    '\u{0C66}',
+    '\u{0CE6}',
+    '\u{0D66}',
+    '\u{0DE6}',
+    '\u{0E50}',
+    '\u{0ED0}',
+    '\u{0F20}',
+    '\u{1040}',
+    '\u{1090}',
+    '\u{17E0}',
+    '\u{1810}',
+    '\u{1946}',
+    '\u{19D0}',
+    '\u{1A80}',
+    '\u{1A90}',
+    '\u{1B50}',
+    '\u{1BB0}',
+    '\u{1C40}',
+    '\u{1C50}',
+    '\u{A620}',
+    '\u{A8D0}',
+    '\u{A900}',
+    '\u{A9D0}',
+    '\u{A9F0}',
+    '\u{AA50}',
+    '\u{ABF0}',
+    '\u{FF10}',
+    '\u{104A0}',
+    '\u{10D30}',
+    '\u{11066}',
+    '\u{110F0}',
+    '\u{11136}',
+    '\u{111D0}',
+    '\u{112F0}',
+    '\u{11450}',
+    '\u{114D0}',
+    '\u{11650}',
+    '\u{116C0}',
+    '\u{11730}',
+    '\u{118E0}',
+    '\u{11950}',
+    '\u{11C50}',
+    '\u{11D50}',
+    '\u{11DA0}',
+    '\u{16A60}',
+    '\u{16AC0}',
+    '\u{16B50}',
+    '\u{1D7CE}',
+    '\u{1D7D8}',
+    '\u{1D7E2}',
+    '\u{1D7EC}',
+    '\u{1D7F6}',
+    '\u{1E140}',
+    '\u{1E2F0}',
+    '\u{1E950}',
+    '\u{1FBF0}',
+];
+
+/// Numeric value of a Unicode decimal digit (e.g. `7`, `７` or `٧`).
+#[must_use]
+pub fn decimal_value(c: char) -> Option<usize> {
+    let block = DECIMAL_ZEROS
+        .partition_point(|&zero| zero <= c)
+        .checked_sub(1)?;
+    let value = u32::from(c) - u32::from(DECIMAL_ZEROS[block]);
+
+    // Blocks hold ten digits each, so value is a single digit whenever it's in range.
+    (value < 10).then_some(value as usize)
+}
+
+/// Folds text for matching regardless of case and normalisation form: every character is
+/// decomposed on its own with compatibility decomposition (NFKD) and lowercased.
+///
+/// Strings equal in NFC or NFKC fold to the same string, so tokens match in any of these forms. The
+/// only exception are characters stacking several combining marks, which are not reordered.
+pub fn fold(text: &str) -> String {
+    text.chars().flat_map(fold_char).collect()
+}
+
+/// Folded form of a single character.
+pub(crate) fn fold_char(c: char) -> impl Iterator<Item = char> {
+    iter::once(c).nfkd().flat_map(char::to_lowercase)
+}
@@ -0,0 +1,71 @@
+use aoc_common::Diagnostics;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_2::{parse_games, power_of_games, sum_of_possible_games, Bag};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+/// Games of three to six rounds, each round revealing up to three colours.
+fn synthetic(games: usize) -> String {
+    (1..=games)
+        .map(|id| {
+            let rounds = (0..3 + id % 4)
+                .map(|round| {
+                    let seed = id * 31 + round * 17;
+                    format!(
+                        "{} red, {} green, {} blue",
+                        1 + seed % 15,
+                        1 + (seed * 7) % 15,
+                        1 + (seed * 13) % 15
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+            format!("Game {id}: {rounds}\n")
+        })
+        .collect()
+}
+
+fn inputs() -> [(&'static str, String); 2] {
+    [
+        ("example", EXAMPLE.to_owned()),
+        ("synthetic", synthetic(10_000)),
+    ]
+}
+
+fn parsers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-2/parse");
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(BenchmarkId::new("parse_games", name), input, |b, input| {
+            b.iter(|| parse_games(black_box(input), &mut Diagnostics::default()));
+        });
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    let bag = Bag::default();
+    let mut group = c.benchmark_group("day-2");
+
+    for (name, input) in &inputs() {
+        let games = parse_games(input, &mut Diagnostics::default()).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("sum_of_possible_games", name),
+            &games,
+            |b, games| b.iter(|| sum_of_possible_games(black_box(games), &bag)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("power_of_games", name),
+            &games,
+            |b, games| {
+                b.iter(|| power_of_games(black_box(games)));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parsers, solvers);
+criterion_main!(benches);
@@ -0,0 +1,23 @@
+use crate::Game;
+
+/// Header line of CSV export, see [`csv_rows`].
+pub const CSV_HEADER: &str = "game,round,colour,count";
+
+/// Rows of CSV export of the game, one per colour of every round with rounds numbered from 1.
+pub fn csv_rows(game: &Game) -> impl Iterator<Item = String> + '_ {
+    game.rounds()
+        .iter()
+        .enumerate()
+        .flat_map(move |(index, round)| {
+            round
+                .cubes
+                .iter()
+                .map(move |(colour, count)| format!("{},{},{colour},{count}", game.id, index + 1))
+        })
+}
+
+/// JSON object of the game on a single line, e.g. `{"id":1,"rounds":[{"blue":3,"red":4}]}`.
+#[must_use]
+pub fn to_json(game: &Game) -> String {
+    aoc_common::json_line(game)
+}
@@ -0,0 +1,363 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display},
+};
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{Colour, Game, Round};
+
+/// Largest number of grid points a single estimate may evaluate, and bound on `max_cubes` times the
+/// number of colours, as terms of the likelihood are tabulated for every size of bag up to that.
+/// Keeps the posterior weights as well as every table within 128 MiB.
+pub const MAX_GRID_POINTS: u64 = 1 << 24;
+
+/// Estimates bag contents from observed rounds with a Bayesian posterior over cube counts.
+///
+/// Every round is assumed to draw all of its cubes from the bag at once, without replacement, with
+/// cubes put back between rounds. The likelihood of a round is then multivariate hypergeometric and
+/// the prior is uniform over every count of every colour up to `max_cubes`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Inference {
+    /// Largest number of cubes of a single colour considered.
+    pub max_cubes: u32,
+    /// Probability mass of the posterior within reported intervals, e.g. `0.95`.
+    pub credibility: f64,
+}
+
+impl Default for Inference {
+    fn default() -> Self {
+        Self {
+            max_cubes: 20,
+            credibility: 0.95,
+        }
+    }
+}
+
+/// Bag contents estimated from rounds of a single game, or of all of them.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Estimate {
+    /// Id of the game, `None` for all games together.
+    pub game: Option<u32>,
+    pub colours: Vec<ColourEstimate>,
+}
+
+/// Estimated number of cubes of a single colour.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ColourEstimate {
+    pub colour: Colour,
+    /// Count of the most probable bag (maximum a posteriori).
+    pub map: u32,
+    /// Mean of the marginal posterior.
+    pub mean: f64,
+    /// Equal-tailed credible interval of the marginal posterior, inclusive.
+    pub low: u32,
+    pub high: u32,
+}
+
+impl Display for Estimate {
+    /// Renders the estimate as a single line, e.g.
+    /// `game 1: blue 6 [6, 14], green 2 [2, 11], red 4 [4, 13]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.game {
+            Some(id) => write!(f, "game {id}:")?,
+            None => write!(f, "all games:")?,
+        }
+        for (index, estimate) in self.colours.iter().enumerate() {
+            let separator = if index > 0 { "," } else { "" };
+            write!(
+                f,
+                "{separator} {} {} [{}, {}]",
+                estimate.colour, estimate.map, estimate.low, estimate.high
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Inference {
+    /// Estimates bag of every game from its own rounds, in order of input.
+    ///
+    /// Every colour seen in any of the games is estimated, as a bag may hold cubes never drawn.
+    ///
+    /// # Errors
+    ///
+    /// Fails when a game draws more cubes of a colour than `max_cubes`, or the grid or bags are too
+    /// large.
+    pub fn games(&self, games: &[Game]) -> Result<Vec<Estimate>, InferError> {
+        let colours = colours(games);
+        games
+            .iter()
+            .map(|game| {
+                self.check(game, &colours)?;
+                self.estimate(Some(game.id), &colours, game.rounds())
+            })
+            .collect()
+    }
+
+    /// Estimates a single bag all of the games were played with.
+    ///
+    /// # Errors
+    ///
+    /// Fails when a game draws more cubes of a colour than `max_cubes`, or the grid or bags are too
+    /// large.
+    pub fn global(&self, games: &[Game]) -> Result<Estimate, InferError> {
+        let colours = colours(games);
+        for game in games {
+            self.check(game, &colours)?;
+        }
+
+        let rounds = games
+            .iter()
+            .flat_map(|game| game.rounds().iter().cloned())
+            .collect::<Vec<_>>();
+        self.estimate(None, &colours, &rounds)
+    }
+
+    fn check(&self, game: &Game, colours: &[Colour]) -> Result<(), InferError> {
+        for round in game.rounds() {
+            for colour in colours {
+                let count = round.cubes.get(colour);
+                if count > self.max_cubes {
+                    return Err(InferError::Exceeds {
+                        game: game.id,
+                        colour: colour.clone(),
+                        count,
+                        max_cubes: self.max_cubes,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn estimate(
+        &self,
+        game: Option<u32>,
+        colours: &[Colour],
+        rounds: &[Round],
+    ) -> Result<Estimate, InferError> {
+        let max = self.max_cubes as usize;
+        if (max as u64).saturating_mul(colours.len() as u64) >= MAX_GRID_POINTS {
+            return Err(InferError::BagsTooLarge {
+                colours: colours.len(),
+                max_cubes: self.max_cubes,
+            });
+        }
+        // Counts below the largest draw cannot explain it and are left out of the grid.
+        let mut lows = vec![0; colours.len()];
+        for round in rounds {
+            for (low, colour) in lows.iter_mut().zip(colours) {
+                *low = (*low).max(round.cubes.get(colour) as usize);
+            }
+        }
+        let points = lows
+            .iter()
+            .map(|&low| (max - low + 1) as u64)
+            .try_fold(1_u64, u64::checked_mul)
+            .filter(|&points| points <= MAX_GRID_POINTS)
+            .ok_or(InferError::GridTooLarge {
+                colours: colours.len(),
+                max_cubes: self.max_cubes,
+            })?;
+
+        // Log-likelihoods are turned into posterior weights in place, so the grid is held once.
+        let mut weights = self.ln_likelihoods(colours, rounds, &lows, points);
+
+        let (best, peak) = weights
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap_or_default();
+        for weight in &mut weights {
+            *weight = (*weight - peak).exp();
+        }
+        let total = weights.iter().sum::<f64>();
+
+        let mut marginals = lows
+            .iter()
+            .map(|&low| vec![0.0; max - low + 1])
+            .collect::<Vec<_>>();
+        let mut map = lows.clone();
+        for (point, weight) in weights.iter().enumerate() {
+            let mut rest = point;
+            for (index, marginal) in marginals.iter_mut().enumerate().rev() {
+                let offset = rest % marginal.len();
+                rest /= marginal.len();
+                marginal[offset] += weight / total;
+                if point == best {
+                    map[index] = lows[index] + offset;
+                }
+            }
+        }
+
+        let tail = (1.0 - self.credibility) / 2.0;
+        let colours = colours
+            .iter()
+            .zip(marginals)
+            .zip(lows.iter().zip(map))
+            .map(|((colour, marginal), (&low, map))| {
+                let quantile = |probability: f64| {
+                    let mut cumulative = 0.0;
+                    let offset = marginal
+                        .iter()
+                        .position(|&mass| {
+                            cumulative += mass;
+                            cumulative >= probability
+                        })
+                        .unwrap_or(marginal.len() - 1);
+                    count(low + offset)
+                };
+                ColourEstimate {
+                    colour: colour.clone(),
+                    map: count(map),
+                    mean: marginal
+                        .iter()
+                        .enumerate()
+                        .map(|(offset, mass)| f64::from(count(low + offset)) * mass)
+                        .sum(),
+                    low: quantile(tail),
+                    high: quantile(1.0 - tail),
+                }
+            })
+            .collect();
+
+        Ok(Estimate { game, colours })
+    }
+
+    /// Log-likelihood of the rounds at every grid point, with counts of colours from `lows` up to
+    /// `max_cubes`.
+    fn ln_likelihoods(
+        &self,
+        colours: &[Colour],
+        rounds: &[Round],
+        lows: &[usize],
+        points: u64,
+    ) -> Vec<f64> {
+        let max = self.max_cubes as usize;
+        let factorials = LnFactorials::new(max * colours.len());
+        // Log-likelihood separates into a term per colour and count, and a term per bag size.
+        let by_colour = colours
+            .iter()
+            .map(|colour| {
+                (0..=max)
+                    .map(|count| {
+                        rounds
+                            .iter()
+                            .map(|round| {
+                                factorials.ln_choose(count, round.cubes.get(colour) as usize)
+                            })
+                            .sum::<f64>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let drawn = rounds
+            .iter()
+            .map(|round| {
+                colours
+                    .iter()
+                    .map(|colour| round.cubes.get(colour) as usize)
+                    .sum::<usize>()
+            })
+            .collect::<Vec<_>>();
+        let by_size = (0..=max * colours.len())
+            .map(|size| {
+                drawn
+                    .iter()
+                    .map(|&k| factorials.ln_choose(size, k))
+                    .sum::<f64>()
+            })
+            .collect::<Vec<_>>();
+
+        // Grid points in mixed radix order, the last colour changing fastest.
+        let mut counts = lows.to_vec();
+        let mut ln_likelihoods = Vec::with_capacity(usize::try_from(points).unwrap_or_default());
+        loop {
+            let size = counts.iter().sum::<usize>();
+            let ln_likelihood = counts
+                .iter()
+                .zip(&by_colour)
+                .map(|(&count, terms)| terms[count])
+                .sum::<f64>()
+                - by_size[size];
+            ln_likelihoods.push(ln_likelihood);
+
+            let Some(index) = (0..counts.len()).rev().find(|&index| counts[index] < max) else {
+                break;
+            };
+            counts[index] += 1;
+            counts[index + 1..].copy_from_slice(&lows[index + 1..]);
+        }
+
+        ln_likelihoods
+    }
+}
+
+/// Every colour seen in any of the games, in alphabetical order.
+fn colours(games: &[Game]) -> Vec<Colour> {
+    games
+        .iter()
+        .flat_map(|game| game.rounds().iter().flat_map(|round| round.cubes.colours()))
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Grid counts never exceed `max_cubes`, which is `u32`.
+fn count(count: usize) -> u32 {
+    u32::try_from(count).unwrap_or(u32::MAX)
+}
+
+/// Table of natural logarithms of factorials.
+#[derive(Clone, Debug)]
+pub struct LnFactorials(Vec<f64>);
+
+impl LnFactorials {
+    /// Table of `ln(n!)` for every `n` up to `max`.
+    #[must_use]
+    pub fn new(max: usize) -> Self {
+        let mut table = Vec::with_capacity(max + 1);
+        let (mut n, mut ln) = (0.0_f64, 0.0);
+        table.push(ln);
+        for _ in 0..max {
+            n += 1.0;
+            ln += n.ln();
+            table.push(ln);
+        }
+        Self(table)
+    }
+
+    /// Natural logarithm of binomial coefficient `n` choose `k`, negative infinity when `k > n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is out of the table.
+    #[must_use]
+    pub fn ln_choose(&self, n: usize, k: usize) -> f64 {
+        if k > n {
+            return f64::NEG_INFINITY;
+        }
+        self.0[n] - self.0[k] - self.0[n - k]
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum InferError {
+    #[error("Game {game} draws {count} {colour} cubes, more than {max_cubes} considered")]
+    Exceeds {
+        game: u32,
+        colour: Colour,
+        count: u32,
+        max_cubes: u32,
+    },
+    #[error("Grid of {colours} colours with up to {max_cubes} cubes each is too large")]
+    GridTooLarge { colours: usize, max_cubes: u32 },
+    #[error(
+        "Bags of {colours} colours with up to {max_cubes} cubes each are too large to tabulate"
+    )]
+    BagsTooLarge { colours: usize, max_cubes: u32 },
+}
@@ -0,0 +1,516 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+//! Day 2: Cube Conundrum.
+//!
+//! Every [`Game`] record lists rounds of cubes of any colours drawn from a bag. Part one sums ids of
+//! games possible with a given [`Bag`], part two sums powers of minimal bags needed for every game.
+
+mod export;
+mod infer;
+mod query;
+mod report;
+
+use std::{
+    borrow::Borrow,
+    collections::{btree_map, BTreeMap, BTreeSet},
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use aoc_common::{Answer, Diagnostics, ParseError, Solver};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub use export::{csv_rows, to_json, CSV_HEADER};
+pub use infer::{ColourEstimate, Estimate, InferError, Inference, LnFactorials, MAX_GRID_POINTS};
+pub use query::{minimal_bag, possible_games, smallest_bag_for, Bags, BagsError};
+pub use report::{rejections, Excess, Rejection};
+
+/// Solver of day 2, checking games against its [`Bag`].
+#[derive(Clone, Debug, Default)]
+pub struct Day2 {
+    pub bag: Bag,
+}
+
+impl Solver for Day2 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        Ok(sum_of_possible_games(&parse_games(input, diagnostics)?, &self.bag).into())
+    }
+
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        Ok(power_of_games(&parse_games(input, diagnostics)?).into())
+    }
+}
+
+/// Cubes loaded into the bag before the games were played.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Bag {
+    pub cubes: Cubes,
+}
+
+impl Default for Bag {
+    /// Bag from the puzzle description: 12 red, 13 green and 14 blue cubes.
+    fn default() -> Self {
+        Self {
+            cubes: [("red", 12), ("green", 13), ("blue", 14)]
+                .into_iter()
+                .map(|(colour, count)| (Colour::from(colour), count))
+                .collect(),
+        }
+    }
+}
+
+impl Bag {
+    /// Whether every round of the game could have been drawn from this bag.
+    #[must_use]
+    pub fn allows(&self, game: &Game) -> bool {
+        game.first_impossible_round(self).is_none()
+    }
+
+    /// Whether all the cubes could have been drawn from this bag at once.
+    #[must_use]
+    pub fn holds(&self, cubes: &Cubes) -> bool {
+        cubes
+            .iter()
+            .all(|(colour, &count)| count <= self.cubes.get(colour))
+    }
+}
+
+impl Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.cubes.is_empty() {
+            return f.write_str("no cubes");
+        }
+        self.cubes.fmt(f)
+    }
+}
+
+/// Parses every game record, one per line.
+///
+/// # Errors
+///
+/// Fails on the first line which is not a valid game record, unless it's dropped in lenient mode.
+pub fn parse_games(contents: &str, diagnostics: &mut Diagnostics) -> Result<Vec<Game>, ParseError> {
+    let mut games = Vec::new();
+
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            diagnostics.ignore(ParseError::at(contents, line, "unexpected blank line"))?;
+            continue;
+        }
+
+        let game = diagnostics.within(contents, line, |diagnostics| Game::parse(line, diagnostics));
+        games.extend(diagnostics.record(game)?);
+    }
+
+    Ok(games)
+}
+
+/// Sums powers (product of per-colour maxima) of all games.
+///
+/// Every colour revealed in any of the games counts, so a game never revealing some of them has
+/// power of 0.
+#[must_use]
+pub fn power_of_games(games: &[Game]) -> u64 {
+    let colours = games
+        .iter()
+        .flat_map(|game| game.rounds().iter().flat_map(|round| round.cubes.colours()))
+        .collect::<BTreeSet<_>>();
+
+    games
+        .iter()
+        .map(|game| {
+            let cubes = game.max_cubes();
+            colours
+                .iter()
+                .map(|colour| u64::from(cubes.get(colour)))
+                .product::<u64>()
+        })
+        .sum()
+}
+
+/// Sums ids of games possible with given bag.
+#[must_use]
+pub fn sum_of_possible_games(games: &[Game], bag: &Bag) -> u64 {
+    games
+        .iter()
+        .filter(|game| bag.allows(game))
+        .map(|game| u64::from(game.id))
+        .sum()
+}
+
+/// Game record with all of its rounds in order.
+///
+/// Games have at least one round, every round reveals at least one colour and every colour is a
+/// word made of letters, so that every game is written down as a record which parses back.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "GameRecord")]
+pub struct Game {
+    pub id: u32,
+    rounds: Vec<Round>,
+}
+
+/// Fields of [`Game`] as deserialized, before they are checked.
+#[derive(Deserialize)]
+struct GameRecord {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GameError {
+    #[error("Game has no rounds")]
+    NoRounds,
+    #[error("Round {0} reveals no cubes")]
+    EmptyRound(usize),
+    #[error("Invalid colour `{0}`")]
+    InvalidColour(Colour),
+}
+
+impl TryFrom<GameRecord> for Game {
+    type Error = GameError;
+
+    fn try_from(record: GameRecord) -> Result<Self, Self::Error> {
+        Game::new(record.id, record.rounds)
+    }
+}
+
+impl Game {
+    /// Game of the rounds.
+    ///
+    /// # Errors
+    ///
+    /// Fails when there are no rounds, any round is empty or any colour is not a word made of
+    /// letters.
+    pub fn new(id: u32, rounds: Vec<Round>) -> Result<Self, GameError> {
+        if rounds.is_empty() {
+            return Err(GameError::NoRounds);
+        }
+        for (index, round) in rounds.iter().enumerate() {
+            if round.cubes.is_empty() {
+                return Err(GameError::EmptyRound(index + 1));
+            }
+            if let Some(colour) = round.cubes.colours().find(|colour| !is_word(colour)) {
+                return Err(GameError::InvalidColour(colour.clone()));
+            }
+        }
+
+        Ok(Self { id, rounds })
+    }
+
+    /// Rounds of the game in order.
+    #[must_use]
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    /// Parses `Game <id>: <round>; <round>; ...` record.
+    ///
+    /// # Errors
+    ///
+    /// Fails when record has no `:` separator, its id is not a number or any of its rounds is malformed.
+    /// Strict mode also rejects records missing the `Game` label. Rounds left empty by entries dropped
+    /// in lenient mode are dropped too, and so is the game when no round is left.
+    pub fn parse(record: &str, diagnostics: &mut Diagnostics) -> Result<Self, ParseError> {
+        let Some((id, rounds)) = record.split_once(':') else {
+            return Err(ParseError::at(
+                record,
+                record,
+                "expected `Game <id>: <rounds>`",
+            ));
+        };
+
+        // ID part should follow given schema 'Game <uint id>' but we need only '<numeric id>' part.
+        let number = if let Some(number) = id.trim().strip_prefix("Game ") {
+            number.trim()
+        } else {
+            diagnostics.ignore(ParseError::at(record, id, "expected `Game <id>` label"))?;
+            id.trim()
+        };
+        let Ok(id) = number.parse::<u32>() else {
+            return Err(ParseError::at(
+                record,
+                id,
+                format!("invalid game id `{number}`"),
+            ));
+        };
+
+        let text = rounds;
+        let mut rounds = text
+            .split(';')
+            .map(|round| {
+                diagnostics.within(record, round, |diagnostics| {
+                    Round::parse(round, diagnostics)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        rounds.retain(|round| !round.cubes.is_empty());
+        if rounds.is_empty() {
+            return Err(ParseError::at(record, text, "game has no rounds"));
+        }
+
+        Ok(Game { id, rounds })
+    }
+
+    /// Largest number of cubes of every colour revealed in any of the rounds, i.e. the minimal bag
+    /// making the game possible.
+    #[must_use]
+    pub fn max_cubes(&self) -> Cubes {
+        let mut cubes = Cubes::default();
+        for round in &self.rounds {
+            cubes.merge_max(&round.cubes);
+        }
+        cubes
+    }
+
+    /// Number of cubes revealed in all the rounds together.
+    #[must_use]
+    pub fn total_cubes(&self) -> u64 {
+        self.rounds.iter().map(Round::total_cubes).sum()
+    }
+
+    /// Index of the first round which could not have been drawn from the bag, if any.
+    #[must_use]
+    pub fn first_impossible_round(&self, bag: &Bag) -> Option<usize> {
+        self.rounds
+            .iter()
+            .position(|round| !bag.holds(&round.cubes))
+    }
+}
+
+impl Display for Game {
+    /// Formats the game as a canonical record, e.g. `Game 1: 3 blue, 4 red; 2 green, 6 blue`, which
+    /// [`Game::parse`] reads back into the same game.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+        for (index, round) in self.rounds.iter().enumerate() {
+            let separator = if index > 0 { ";" } else { "" };
+            write!(f, "{separator} {round}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Cubes revealed in a single round.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Round {
+    pub cubes: Cubes,
+}
+
+impl Round {
+    /// Parses comma separated `<count> <colour>` entries.
+    ///
+    /// # Errors
+    ///
+    /// Fails on the first entry which is not a valid [`Dice`], unless it's dropped in lenient mode.
+    /// Cubes of a colour repeated within the round are added up, but strict mode rejects them, and
+    /// so does any mode when the sum overflows.
+    pub fn parse(round: &str, diagnostics: &mut Diagnostics) -> Result<Round, ParseError> {
+        let mut cubes = Cubes::default();
+
+        for entry in round.split(',') {
+            let dice = diagnostics.within(round, entry, |_| Dice::parse(entry));
+            let Some(Dice { colour, count }) = diagnostics.record(dice)? else {
+                continue;
+            };
+
+            let Some(&known) = cubes.0.get(&colour) else {
+                cubes.insert(colour, count);
+                continue;
+            };
+            let message = format!("colour `{colour}` repeated within round");
+            diagnostics.ignore(ParseError::at(round, entry.trim(), message))?;
+            let Some(sum) = known.checked_add(count) else {
+                return Err(ParseError::at(
+                    round,
+                    entry.trim(),
+                    format!("number of `{colour}` cubes in round is too large"),
+                ));
+            };
+            cubes.insert(colour, sum);
+        }
+
+        Ok(Self { cubes })
+    }
+
+    /// Number of cubes revealed in the round.
+    #[must_use]
+    pub fn total_cubes(&self) -> u64 {
+        self.cubes.total()
+    }
+}
+
+impl Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cubes.fmt(f)
+    }
+}
+
+/// Single `<count> <colour>` entry of a round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dice {
+    pub colour: Colour,
+    pub count: u32,
+}
+
+impl Dice {
+    /// Parses `<count> <colour>` entry, surrounding whitespace is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Fails when count is not a number or colour is not a word made of letters.
+    pub fn parse(entry: &str) -> Result<Self, ParseError> {
+        let trimmed = entry.trim();
+        let Some((count, colour)) = trimmed.split_once(' ') else {
+            let fragment = if trimmed.is_empty() { entry } else { trimmed };
+            return Err(ParseError::at(
+                entry,
+                fragment,
+                "expected `<count> <colour>`",
+            ));
+        };
+        let Ok(count) = count.parse::<u32>() else {
+            return Err(ParseError::at(
+                entry,
+                count,
+                format!("invalid cube count `{count}`"),
+            ));
+        };
+
+        let colour = colour.trim();
+        if !is_word(colour) {
+            return Err(ParseError::at(
+                entry,
+                colour,
+                format!("invalid colour `{colour}`"),
+            ));
+        }
+
+        Ok(Dice {
+            colour: colour.into(),
+            count,
+        })
+    }
+}
+
+/// Colour of cubes, e.g. `red`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Colour(pub String);
+
+impl From<&str> for Colour {
+    fn from(colour: &str) -> Self {
+        Self(colour.to_owned())
+    }
+}
+
+impl Deref for Colour {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Colour {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Number of cubes of every colour. Colours missing from it have no cubes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Cubes(BTreeMap<Colour, u32>);
+
+impl Cubes {
+    /// Number of cubes of given colour.
+    #[must_use]
+    pub fn get(&self, colour: &str) -> u32 {
+        self.0.get(colour).copied().unwrap_or_default()
+    }
+
+    /// Sets number of cubes of given colour.
+    pub fn insert(&mut self, colour: Colour, count: u32) {
+        self.0.insert(colour, count);
+    }
+
+    /// Raises number of cubes of every colour to at least its number in `other`.
+    pub fn merge_max(&mut self, other: &Cubes) {
+        for (colour, &count) in &other.0 {
+            let known = self.0.entry(colour.clone()).or_default();
+            *known = (*known).max(count);
+        }
+    }
+
+    /// Whether no colour has any cubes recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Number of cubes of all colours together.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.0.values().map(|&count| u64::from(count)).sum()
+    }
+
+    /// Colours with any cubes recorded, in alphabetical order.
+    pub fn colours(&self) -> impl Iterator<Item = &Colour> {
+        self.0.keys()
+    }
+
+    /// Colours together with number of their cubes, in alphabetical order of colours.
+    pub fn iter(&self) -> btree_map::Iter<'_, Colour, u32> {
+        self.0.iter()
+    }
+}
+
+impl Display for Cubes {
+    /// Formats cubes as `<count> <colour>` entries separated by `, `, in alphabetical order of
+    /// colours.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (colour, count)) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{count} {colour}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<(Colour, u32)> for Cubes {
+    fn from_iter<T: IntoIterator<Item = (Colour, u32)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a Cubes {
+    type Item = (&'a Colour, &'a u32);
+    type IntoIter = btree_map::Iter<'a, Colour, u32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Whether the colour is a non-empty word made of letters.
+fn is_word(colour: &str) -> bool {
+    !colour.is_empty() && colour.chars().all(char::is_alphabetic)
+}
+
+#[cfg(test)]
+mod test;
@@ -1,220 +1,342 @@
 #![warn(clippy::all)]
 #![warn(clippy::pedantic)]
-use std::{ops::Deref, path::PathBuf, process::exit};
+use std::{path::PathBuf, process::ExitCode};
 
+use aoc_common::{csv_row, json_line, report_dropped, CommonArgs, Diagnostics, Format, Input};
 use clap::{ArgAction, Parser};
-use tracing::{error, Level};
-use tracing_subscriber::FmtSubscriber;
+use day_2::{
+    csv_rows, minimal_bag, parse_games, possible_games, rejections, smallest_bag_for, to_json, Bag,
+    Bags, Colour, Day2, Estimate, Game, Inference, CSV_HEADER,
+};
+use tracing::error;
 
-fn main() {
+fn main() -> ExitCode {
     let args = Args::parse();
+    args.common.init_logging();
 
-    let log_level = match args.verbose {
-        0 => Level::ERROR,
-        1 => Level::WARN,
-        2 => Level::INFO,
-        3 => Level::DEBUG,
-        _ => Level::TRACE,
-    };
-
-    let subscriber = FmtSubscriber::builder().with_max_level(log_level).finish();
-    if let Err(error) = tracing::subscriber::set_global_default(subscriber) {
-        eprintln!("[ERROR] Cannot set up logging.");
-        eprintln!("Error cause: {error}");
+    let bag = if args.cubes.is_empty() {
+        Bag::default()
+    } else {
+        Bag {
+            cubes: args.cubes.iter().cloned().collect(),
+        }
     };
 
-    if args.filename.is_none() {
-        error!("Inputs other than file are currently not supported.");
-        return;
+    if args.rejected {
+        return report_rejections(&args, &bag);
+    }
+    if args.export {
+        return export(&args);
+    }
+    if args.infer {
+        return infer(&args);
+    }
+    if args.minimal_bag || args.at_least.is_some() || args.bags.is_some() {
+        return query(&args);
     }
 
-    let Ok(contents) = std::fs::read_to_string(args.filename.as_ref().unwrap()) else {
-        error!("Cannot read file contents.");
-        return;
-    };
+    aoc_common::run(&Day2 { bag }, &args.common)
+}
 
-    let mut games = Vec::with_capacity(10);
+/// Prints every game impossible with the bag and the draws which made it so instead of solving the
+/// puzzle.
+fn report_rejections(args: &Args, bag: &Bag) -> ExitCode {
+    let Some(inputs) = read_games(args) else {
+        return ExitCode::FAILURE;
+    };
 
-    for line in contents.lines() {
-        let Some(game) = Game::parse(line) else {
-            error!("Cannot parse game record.");
-            continue;
-        };
-        games.push(game);
+    let format = args.common.format;
+    if format == Format::Csv {
+        let header = csv_row(&[
+            &"input", &"game", &"round", &"colour", &"count", &"limit", &"excess",
+        ]);
+        println!("{header}");
+    }
+    for (input, games) in inputs {
+        if format == Format::Text {
+            if let Some(header) = input.header() {
+                println!("{header}");
+            }
+        }
+        for rejection in rejections(&games, bag) {
+            match format {
+                Format::Text => print!("{rejection}"),
+                Format::Json => println!("{}", json_line(&rejection)),
+                Format::Csv => {
+                    for excess in &rejection.excesses {
+                        let row = csv_row(&[
+                            &input.name(),
+                            &rejection.game,
+                            &excess.round,
+                            &excess.colour,
+                            &excess.count,
+                            &excess.limit,
+                            &excess.excess,
+                        ]);
+                        println!("{row}");
+                    }
+                }
+            }
+        }
     }
 
-    let sum = sum_of_possible_games(&games, &args);
-    let power = power_of_games(&games);
-
-    println!("{sum}");
-    println!("{power}");
+    ExitCode::SUCCESS
 }
 
-fn power_of_games(games: &[Game]) -> u32 {
-    games
-        .iter()
-        .map(|game| *game.red * *game.green * *game.blue)
-        .sum()
-}
+/// Prints answers to bag queries given on command line for every input instead of solving the
+/// puzzle.
+fn query(args: &Args) -> ExitCode {
+    let bags = match args.bags.as_deref().map(Bags::load).transpose() {
+        Ok(bags) => bags,
+        Err(error) => {
+            error!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(inputs) = read_games(args) else {
+        return ExitCode::FAILURE;
+    };
 
-fn sum_of_possible_games(games: &[Game], args: &Args) -> u32 {
-    return games
-        .iter()
-        .filter(|game| {
-            *game.red <= args.reds && *game.green <= args.greens && *game.blue <= args.blues
-        })
-        .map(|game| game.id)
-        .reduce(|u, v| u + v)
-        .unwrap_or(0);
-}
+    for (input, games) in inputs {
+        if let Some(header) = input.header() {
+            println!("{header}");
+        }
 
-#[derive(Clone, Debug, Default, PartialEq)]
-struct Game {
-    id: u32,
-    red: Red,
-    green: Green,
-    blue: Blue,
-}
+        if args.minimal_bag {
+            let selected = games
+                .iter()
+                .filter(|game| args.games.is_empty() || args.games.contains(&game.id))
+                .collect::<Vec<_>>();
+            if let Some(id) = args
+                .games
+                .iter()
+                .find(|&&id| selected.iter().all(|game| game.id != id))
+            {
+                error!("No game {id} in {}", input.name());
+                return ExitCode::FAILURE;
+            }
+            let bag = minimal_bag(selected);
+            println!("minimal bag: {bag} ({} cubes)", bag.cubes.total());
+        }
 
-impl Game {
-    fn parse(record: &str) -> Option<Self> {
-        let data: Vec<_> = record.split(':').collect();
-        if data.len() != 2 {
-            return None;
+        if let Some(count) = args.at_least {
+            match smallest_bag_for(&games, count) {
+                Some(bag) => println!(
+                    "smallest bag for {count} games: {bag} ({} cubes), possible games: {}",
+                    bag.cubes.total(),
+                    ids(&possible_games(&games, &bag))
+                ),
+                None => println!("smallest bag for {count} games: only {} games", games.len()),
+            }
         }
 
-        // We checked that record is correct and we have exactly two parts, so we can unwrap them.
+        for (name, bag) in bags.iter().flat_map(Bags::iter) {
+            println!(
+                "bag {name} ({bag}): possible games: {}",
+                ids(&possible_games(&games, bag))
+            );
+        }
+    }
 
-        let Some(id) = data.first() else {
-            unreachable!()
-        };
-        let Some(rounds) = data.last() else {
-            unreachable!()
-        };
+    ExitCode::SUCCESS
+}
 
-        // ID part should follow given schema 'Game <uint id>' but we need only '<numeric id>' part.
-        let Ok(id) = id.trim().trim_start_matches("Game ").trim().parse::<u32>() else {
-            error!("Invalid Game ID: {id}.");
-            exit(0);
-        };
+/// Prints bag contents estimated from rounds of every game and of all games together instead of
+/// solving the puzzle.
+fn infer(args: &Args) -> ExitCode {
+    let Some(inputs) = read_games(args) else {
+        return ExitCode::FAILURE;
+    };
+    let inference = Inference {
+        max_cubes: args.max_cubes,
+        credibility: args.credibility,
+    };
 
-        let rounds: Vec<_> = rounds.split(';').map(Round::parse).collect();
+    let format = args.common.format;
+    if format == Format::Csv {
+        let header = csv_row(&[
+            &"input", &"game", &"colour", &"map", &"mean", &"low", &"high",
+        ]);
+        println!("{header}");
+    }
+    for (input, games) in inputs {
+        let estimates = inference.games(&games).and_then(|mut estimates| {
+            estimates.push(inference.global(&games)?);
+            Ok(estimates)
+        });
+        let estimates = match estimates {
+            Ok(estimates) => estimates,
+            Err(error) => {
+                error!("{error}");
+                return ExitCode::FAILURE;
+            }
+        };
 
-        Some(Game {
-            id,
-            red: rounds
-                .iter()
-                .map(|round| round.red)
-                .max()
-                .unwrap_or_default(),
-            green: rounds
-                .iter()
-                .map(|round| round.green)
-                .max()
-                .unwrap_or_default(),
-            blue: rounds
-                .iter()
-                .map(|rounds| rounds.blue)
-                .max()
-                .unwrap_or_default(),
-        })
+        if format == Format::Text {
+            if let Some(header) = input.header() {
+                println!("{header}");
+            }
+        }
+        for estimate in &estimates {
+            print_estimate(format, &input, estimate);
+        }
     }
+
+    ExitCode::SUCCESS
 }
 
-struct Round {
-    red: Red,
-    green: Green,
-    blue: Blue,
+fn print_estimate(format: Format, input: &Input, estimate: &Estimate) {
+    match format {
+        Format::Text => println!("{estimate}"),
+        Format::Json => println!("{}", json_line(estimate)),
+        Format::Csv => {
+            let game = estimate.game.map(|id| id.to_string()).unwrap_or_default();
+            for colour in &estimate.colours {
+                let row = csv_row(&[
+                    &input.name(),
+                    &game,
+                    &colour.colour,
+                    &colour.map,
+                    &format!("{:.3}", colour.mean),
+                    &colour.low,
+                    &colour.high,
+                ]);
+                println!("{row}");
+            }
+        }
+    }
 }
 
-impl Round {
-    fn parse(round: &str) -> Round {
-        let mut red = Red::default();
-        let mut green = Green::default();
-        let mut blue = Blue::default();
-
-        round
-            .split(',')
-            .map(str::trim)
-            .map(str::split_whitespace)
-            .map(Iterator::collect::<Vec<_>>)
-            .map(|parts| {
-                if parts.len() == 2 {
-                    let Ok(count) = parts.first().unwrap().parse::<u32>() else {
-                        return Dice::Empty;
-                    };
-
-                    match *parts.last().unwrap() {
-                        "red" => Dice::Red(count),
-                        "green" => Dice::Green(count),
-                        "blue" => Dice::Blue(count),
-                        _ => Dice::Empty,
-                    }
-                } else {
-                    Dice::Empty
-                }
-            })
-            .for_each(|dice| match dice {
-                Dice::Empty => {}
-                Dice::Red(counter) => red = Red(counter),
-                Dice::Green(counter) => green = Green(counter),
-                Dice::Blue(counter) => blue = Blue(counter),
-            });
+/// Prints every game in canonical form, as JSON objects or CSV rows instead of solving the puzzle.
+fn export(args: &Args) -> ExitCode {
+    let Some(inputs) = read_games(args) else {
+        return ExitCode::FAILURE;
+    };
 
-        Self { red, green, blue }
+    let format = args.common.format;
+    if format == Format::Csv {
+        println!("{CSV_HEADER}");
+    }
+    for (_, games) in inputs {
+        for game in &games {
+            match format {
+                Format::Text => println!("{game}"),
+                Format::Json => println!("{}", to_json(game)),
+                Format::Csv => csv_rows(game).for_each(|row| println!("{row}")),
+            }
+        }
     }
-}
 
-enum Dice {
-    Empty,
-    Red(u32),
-    Green(u32),
-    Blue(u32),
+    ExitCode::SUCCESS
 }
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-struct Red(u32);
-
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-struct Green(u32);
+/// Reads and parses every input, rendering errors on standard error.
+fn read_games(args: &Args) -> Option<Vec<(Input, Vec<Game>)>> {
+    let inputs = match args.common.read_inputs() {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            error!("{error}");
+            return None;
+        }
+    };
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-struct Blue(u32);
+    let mut parsed = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        let mut diagnostics = Diagnostics::new(args.common.mode());
+        let games = parse_games(&input.contents, &mut diagnostics);
+        report_dropped(&input, &diagnostics);
+        match games {
+            Ok(games) => parsed.push((input, games)),
+            Err(error) => {
+                eprintln!("{}", error.render(&input.name(), &input.contents));
+                return None;
+            }
+        }
+    }
 
-impl Deref for Red {
-    type Target = u32;
+    Some(parsed)
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+fn ids(ids: &[u32]) -> String {
+    if ids.is_empty() {
+        return "none".to_owned();
     }
+    ids.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
-impl Deref for Green {
-    type Target = u32;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
+/// Parses probability strictly between 0 and 1.
+fn parse_credibility(value: &str) -> Result<f64, String> {
+    let credibility = value
+        .parse::<f64>()
+        .map_err(|error| format!("invalid probability `{value}`: {error}"))?;
+    if credibility > 0.0 && credibility < 1.0 {
+        Ok(credibility)
+    } else {
+        Err(format!("probability `{value}` is not between 0 and 1"))
     }
 }
 
-impl Deref for Blue {
-    type Target = u32;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
+/// Parses `<colour>=<count>` entry of the bag.
+fn parse_cube(entry: &str) -> Result<(Colour, u32), String> {
+    let (colour, count) = entry
+        .split_once('=')
+        .ok_or_else(|| format!("expected `<colour>=<count>`, got `{entry}`"))?;
+    let colour = colour.trim();
+    if colour.is_empty() || !colour.chars().all(char::is_alphabetic) {
+        return Err(format!("invalid colour `{colour}`"));
     }
+    let count = count
+        .trim()
+        .parse()
+        .map_err(|error| format!("invalid cube count `{count}`: {error}"))?;
+
+    Ok((colour.into(), count))
 }
 
 #[derive(Parser)]
+#[allow(clippy::struct_excessive_bools)]
 struct Args {
-    #[arg(short, long, value_name = "<FILE>")]
-    filename: Option<PathBuf>,
-    #[arg(short, long, value_name = "<COUNT>", action = ArgAction::Set, default_value_t = 0)]
-    greens: u32,
-    #[arg(short, long, value_name = "<COUNT>", action = ArgAction::Set, default_value_t = 0)]
-    blues: u32,
-    #[arg(short, long, value_name = "<COUNT>", action = ArgAction::Set, default_value_t = 0)]
-    reds: u32,
-    #[arg(short, long, action = ArgAction::Count)]
-    verbose: u8,
+    #[command(flatten)]
+    common: CommonArgs,
+    /// Cubes of a colour in the bag, repeat for every colour; 12 red, 13 green and 14 blue if none given.
+    #[arg(short, long = "cube", value_name = "<COLOUR=COUNT>", action = ArgAction::Append, value_parser = parse_cube)]
+    cubes: Vec<(Colour, u32)>,
+    /// Instead of solving, report every game impossible with the bag, with rounds and colours which
+    /// exceed it.
+    #[arg(long, conflicts_with_all = ["check", "record"])]
+    rejected: bool,
+    /// Instead of solving, report the smallest bag making all the games possible.
+    #[arg(long, conflicts_with_all = ["rejected", "check", "record"])]
+    minimal_bag: bool,
+    /// Ids of games the minimal bag is reported for, all of them if not given.
+    #[arg(
+        long,
+        value_name = "<ID>",
+        value_delimiter = ',',
+        requires = "minimal_bag"
+    )]
+    games: Vec<u32>,
+    /// Instead of solving, report the bag with fewest cubes making at least this many games possible.
+    #[arg(long, value_name = "<COUNT>", conflicts_with_all = ["rejected", "check", "record"])]
+    at_least: Option<usize>,
+    /// Instead of solving, report games possible with every bag of a TOML file holding a table of
+    /// cube counts per bag name.
+    #[arg(long, value_name = "<FILE>", conflicts_with_all = ["rejected", "check", "record"])]
+    bags: Option<PathBuf>,
+    /// Instead of solving, estimate bag contents of every game and of all games together from
+    /// their rounds, assuming cubes are drawn without replacement.
+    #[arg(long, conflicts_with_all = ["rejected", "minimal_bag", "at_least", "bags", "check", "record"])]
+    infer: bool,
+    /// Instead of solving, print every game in canonical form, or in the format given by `--format`.
+    #[arg(long, conflicts_with_all = ["rejected", "minimal_bag", "at_least", "bags", "infer", "check", "record"])]
+    export: bool,
+    /// Largest number of cubes of a single colour considered when estimating bag contents.
+    #[arg(long, value_name = "<COUNT>", default_value_t = Inference::default().max_cubes, requires = "infer")]
+    max_cubes: u32,
+    /// Probability of estimated counts within reported intervals.
+    #[arg(long, value_name = "<PROBABILITY>", default_value_t = Inference::default().credibility, value_parser = parse_credibility, requires = "infer")]
+    credibility: f64,
 }
@@ -0,0 +1,188 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::{Bag, Cubes, Game};
+
+/// Smallest bag making every one of the games possible.
+#[must_use]
+pub fn minimal_bag<'a>(games: impl IntoIterator<Item = &'a Game>) -> Bag {
+    let mut cubes = Cubes::default();
+    for game in games {
+        cubes.merge_max(&game.max_cubes());
+    }
+    Bag { cubes }
+}
+
+/// Ids of games possible with the bag, in order of input.
+#[must_use]
+pub fn possible_games(games: &[Game], bag: &Bag) -> Vec<u32> {
+    games
+        .iter()
+        .filter(|game| bag.allows(game))
+        .map(|game| game.id)
+        .collect()
+}
+
+/// Bag with the smallest total of cubes making at least `count` of the games possible, or `None`
+/// when there are fewer games than that. Of equally large bags the one with fewest cubes of
+/// colours first in alphabetical order wins.
+///
+/// Count of every colour but the last one is tried out of counts seen in any game, with branches
+/// already larger than the best bag found pruned. Count of the last colour is then the smallest one
+/// fitting enough games, so the search takes `O(G · V^(C-1))` time for `G` games, `C` colours and
+/// up to `V` distinct counts of a colour.
+#[must_use]
+pub fn smallest_bag_for(games: &[Game], count: usize) -> Option<Bag> {
+    if count > games.len() {
+        return None;
+    }
+
+    let colours = games
+        .iter()
+        .flat_map(|game| game.rounds().iter().flat_map(|round| round.cubes.colours()))
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    if count == 0 || colours.is_empty() {
+        return Some(Bag {
+            cubes: Cubes::default(),
+        });
+    }
+
+    let search = Search {
+        maxima: games
+            .iter()
+            .map(|game| {
+                let cubes = game.max_cubes();
+                colours.iter().map(|colour| cubes.get(colour)).collect()
+            })
+            .collect(),
+        candidates: (0..colours.len() - 1)
+            .map(|index| {
+                games
+                    .iter()
+                    .map(|game| game.max_cubes().get(&colours[index]))
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect()
+            })
+            .collect(),
+        count,
+    };
+
+    let mut best = None;
+    search.run(&mut Vec::with_capacity(colours.len()), 0, &mut best);
+
+    best.map(|(_, limits)| Bag {
+        cubes: colours
+            .into_iter()
+            .zip(limits)
+            .filter(|&(_, limit)| limit > 0)
+            .collect(),
+    })
+}
+
+/// State of [`smallest_bag_for`] search over colours in alphabetical order.
+struct Search {
+    /// Largest count of every colour for every game.
+    maxima: Vec<Vec<u32>>,
+    /// Distinct counts of every colour but the last one, ascending.
+    candidates: Vec<Vec<u32>>,
+    count: usize,
+}
+
+impl Search {
+    fn run(&self, limits: &mut Vec<u32>, total: u64, best: &mut Option<(u64, Vec<u32>)>) {
+        let fitting = self.maxima.iter().filter(|maxima| {
+            maxima
+                .iter()
+                .zip(limits.iter())
+                .all(|(max, limit)| max <= limit)
+        });
+
+        let Some(candidates) = self.candidates.get(limits.len()) else {
+            let mut last = fitting
+                .map(|maxima| maxima[limits.len()])
+                .collect::<Vec<_>>();
+            if last.len() < self.count {
+                return;
+            }
+            let (_, &mut limit, _) = last.select_nth_unstable(self.count - 1);
+            let total = total + u64::from(limit);
+            if best.as_ref().is_none_or(|(best, _)| total < *best) {
+                let mut limits = limits.clone();
+                limits.push(limit);
+                *best = Some((total, limits));
+            }
+            return;
+        };
+        if fitting.count() < self.count {
+            return;
+        }
+
+        for &limit in candidates {
+            let total = total + u64::from(limit);
+            if best.as_ref().is_some_and(|(best, _)| total >= *best) {
+                break;
+            }
+            limits.push(limit);
+            self.run(limits, total, best);
+            limits.pop();
+        }
+    }
+}
+
+/// Named bags to check games against, loaded from a TOML file with a table of cube counts per bag:
+///
+/// ```toml
+/// [small]
+/// red = 5
+/// green = 5
+///
+/// [puzzle]
+/// red = 12
+/// green = 13
+/// blue = 14
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Bags(BTreeMap<String, Bag>);
+
+impl Bags {
+    /// Loads bags from given file.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the file cannot be read or is not a valid bags file.
+    pub fn load(path: &Path) -> Result<Self, BagsError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| BagsError::Read(path.to_owned(), error))?;
+        toml::from_str(&contents).map_err(|error| BagsError::Parse(path.to_owned(), error))
+    }
+
+    /// Bags together with their names, in alphabetical order of names.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Bag)> {
+        self.0.iter().map(|(name, bag)| (name.as_str(), bag))
+    }
+}
+
+impl FromIterator<(String, Bag)> for Bags {
+    fn from_iter<T: IntoIterator<Item = (String, Bag)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum BagsError {
+    #[error("Cannot read bags file `{0}`: {1}")]
+    Read(PathBuf, #[source] io::Error),
+    #[error("Cannot parse bags file `{0}`: {1}")]
+    Parse(PathBuf, #[source] toml::de::Error),
+}
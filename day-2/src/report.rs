@@ -0,0 +1,78 @@
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+use crate::{Bag, Colour, Game};
+
+/// Game impossible with a bag together with every draw the bag could not hold.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Rejection {
+    pub game: u32,
+    pub excesses: Vec<Excess>,
+}
+
+/// Cubes of a single colour drawn in a round beyond what the bag holds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Excess {
+    /// 1-based round number.
+    pub round: usize,
+    pub colour: Colour,
+    pub count: u32,
+    pub limit: u32,
+    /// Cubes drawn beyond the limit.
+    pub excess: u32,
+}
+
+impl Display for Rejection {
+    /// Renders the rejection as a block of lines, one per excess:
+    ///
+    /// ```text
+    /// game 4
+    ///   round 3: 15 blue, 1 over limit of 14
+    ///   round 3: 14 red, 2 over limit of 12
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "game {}", self.game)?;
+        for excess in &self.excesses {
+            writeln!(
+                f,
+                "  round {}: {} {}, {} over limit of {}",
+                excess.round, excess.count, excess.colour, excess.excess, excess.limit
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Games impossible with the bag, in order of input, each listing all of its draws the bag could
+/// not hold.
+#[must_use]
+pub fn rejections(games: &[Game], bag: &Bag) -> Vec<Rejection> {
+    games
+        .iter()
+        .filter_map(|game| {
+            let excesses = game
+                .rounds()
+                .iter()
+                .enumerate()
+                .flat_map(|(index, round)| {
+                    round.cubes.iter().filter_map(move |(colour, &count)| {
+                        let limit = bag.cubes.get(colour);
+                        (count > limit).then(|| Excess {
+                            round: index + 1,
+                            colour: colour.clone(),
+                            count,
+                            limit,
+                            excess: count - limit,
+                        })
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            (!excesses.is_empty()).then_some(Rejection {
+                game: game.id,
+                excesses,
+            })
+        })
+        .collect()
+}
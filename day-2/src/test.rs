@@ -0,0 +1,263 @@
+use aoc_common::Mode;
+use proptest::prelude::*;
+
+use super::*;
+
+#[test]
+fn test_sum_of_possible_games() {
+    let contents = include_str!("../test.txt");
+    let games = parse_games(contents, &mut Diagnostics::default()).unwrap();
+    let sum = sum_of_possible_games(&games, &Bag::default());
+
+    assert_eq!(8, sum);
+
+    let games = parse_games(
+        "Game 4294967295: 1 red\nGame 1: 1 red\n",
+        &mut Diagnostics::default(),
+    );
+    assert_eq!(
+        sum_of_possible_games(&games.unwrap(), &Bag::default()),
+        4_294_967_296
+    );
+}
+
+#[test]
+fn test_power_of_games() {
+    let contents = include_str!("../test.txt");
+    let games = parse_games(contents, &mut Diagnostics::default()).unwrap();
+    let power = power_of_games(&games);
+
+    assert_eq!(2286, power);
+}
+
+#[test]
+fn test_parse_error_points_at_colour() {
+    let contents = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 blu3\n";
+    let error = parse_games(contents, &mut Diagnostics::default()).unwrap_err();
+
+    assert_eq!(&contents[error.span.start..error.span.end], "blu3");
+    assert_eq!(error.span.line_column(contents), (2, 19));
+}
+
+#[test]
+fn test_parse_modes() {
+    let contents = "Game 1: 3 blue, 4 red\n\nGame 2: 1 blue, 2 blu3; 5 green\n";
+
+    let error = parse_games(contents, &mut Diagnostics::new(Mode::Strict)).unwrap_err();
+    assert_eq!(error.span.line_column(contents), (2, 1));
+
+    let mut diagnostics = Diagnostics::new(Mode::Lenient);
+    let games = parse_games(contents, &mut diagnostics).unwrap();
+    let dropped = diagnostics
+        .dropped()
+        .into_iter()
+        .map(|error| &contents[error.span.start..error.span.end])
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        games.iter().map(|game| game.id).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        (
+            games[1].max_cubes().get("blue"),
+            games[1].max_cubes().get("green")
+        ),
+        (1, 5)
+    );
+    assert_eq!(dropped, vec!["", "blu3"]);
+
+    let contents = "Game 1: 3 blue; 2 blu3; 4 red\nGame 2: 1 blu3\n";
+    let mut diagnostics = Diagnostics::new(Mode::Lenient);
+    let games = parse_games(contents, &mut diagnostics).unwrap();
+    assert_eq!(
+        games.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        vec!["Game 1: 3 blue; 4 red"]
+    );
+    assert_eq!(diagnostics.dropped().len(), 3);
+}
+
+#[test]
+fn test_any_colours() {
+    let contents = "Game 1: 3 yellow, 4 red; 2 purple\nGame 2: 5 purple, 1 yellow\n";
+    let games = parse_games(contents, &mut Diagnostics::default()).unwrap();
+    let bag = Bag {
+        cubes: [("yellow", 3), ("purple", 5), ("red", 4)]
+            .into_iter()
+            .map(|(colour, count)| (Colour::from(colour), count))
+            .collect(),
+    };
+
+    assert_eq!(sum_of_possible_games(&games, &bag), 3);
+    assert_eq!(sum_of_possible_games(&games, &Bag::default()), 0);
+    assert_eq!(power_of_games(&games), 3 * 2 * 4);
+}
+
+#[test]
+fn test_rounds() {
+    let contents = include_str!("../test.txt");
+    let games = parse_games(contents, &mut Diagnostics::default()).unwrap();
+    let bag = Bag::default();
+
+    assert_eq!(
+        games
+            .iter()
+            .map(|game| game.rounds().len())
+            .collect::<Vec<_>>(),
+        vec![3, 3, 3, 3, 2]
+    );
+    assert_eq!(games[0].total_cubes(), 18);
+    assert_eq!(
+        games
+            .iter()
+            .map(|game| game.first_impossible_round(&bag))
+            .collect::<Vec<_>>(),
+        vec![None, None, Some(0), Some(2), None]
+    );
+}
+
+#[test]
+fn test_rejections() {
+    let contents = include_str!("../test.txt");
+    let games = parse_games(contents, &mut Diagnostics::default()).unwrap();
+    let rejections = rejections(&games, &Bag::default());
+
+    assert_eq!(
+        rejections
+            .iter()
+            .map(|rejection| rejection.game)
+            .collect::<Vec<_>>(),
+        vec![3, 4]
+    );
+    assert_eq!(
+        rejections[1].to_string(),
+        "game 4\n  round 3: 15 blue, 1 over limit of 14\n  round 3: 14 red, 2 over limit of 12\n"
+    );
+    assert_eq!(
+        serde_json::to_string(&rejections[0]).unwrap(),
+        r#"{"game":3,"excesses":[{"round":1,"colour":"red","count":20,"limit":12,"excess":8}]}"#
+    );
+}
+
+#[test]
+fn test_bag_queries() {
+    let contents = include_str!("../test.txt");
+    let games = parse_games(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(
+        minimal_bag(&games[..2]).to_string(),
+        "6 blue, 3 green, 4 red"
+    );
+    assert_eq!(
+        smallest_bag_for(&games, 4).unwrap().to_string(),
+        "15 blue, 3 green, 14 red"
+    );
+    assert_eq!(smallest_bag_for(&games, 0).unwrap().to_string(), "no cubes");
+    assert_eq!(smallest_bag_for(&games, 6), None);
+
+    let bags: Bags = toml::from_str(
+        "[small]\nred = 5\ngreen = 5\nblue = 5\n\n[puzzle]\nred = 12\ngreen = 13\nblue = 14\n",
+    )
+    .unwrap();
+    assert_eq!(
+        bags.iter()
+            .map(|(name, bag)| (name, possible_games(&games, bag)))
+            .collect::<Vec<_>>(),
+        vec![("puzzle", vec![1, 2, 5]), ("small", vec![2])]
+    );
+}
+
+#[test]
+fn test_inference() {
+    let factorials = LnFactorials::new(10);
+    assert!((factorials.ln_choose(10, 3) - 120_f64.ln()).abs() < 1e-9);
+    assert!(factorials.ln_choose(2, 3).is_infinite());
+
+    // Bags of 1 or 2 cubes of each colour: drawing one of each is certain from the bag of (1, 1),
+    // and has probability of 2/3 from any other, so the bag of (1, 1) has posterior of 1/3.
+    let games = parse_games("Game 1: 1 red, 1 blue\n", &mut Diagnostics::default()).unwrap();
+    let inference = Inference {
+        max_cubes: 2,
+        credibility: 0.5,
+    };
+    let estimate = inference.global(&games).unwrap();
+    let red = &estimate.colours[1];
+
+    assert_eq!(estimate.game, None);
+    assert_eq!((red.map, red.low, red.high), (1, 1, 2));
+    assert!((red.mean - 13.0 / 9.0).abs() < 1e-9);
+    assert_eq!(
+        inference.games(&games).unwrap()[0].to_string(),
+        "game 1: blue 1 [1, 2], red 1 [1, 2]"
+    );
+    assert!(Inference::default()
+        .global(&parse_games("Game 1: 21 red\n", &mut Diagnostics::default()).unwrap())
+        .is_err());
+
+    // Grid of 11 points, but likelihood terms would be tabulated for bags of up to 10^8 cubes.
+    let games = parse_games("Game 1: 99999990 red\n", &mut Diagnostics::default()).unwrap();
+    let inference = Inference {
+        max_cubes: 100_000_000,
+        ..Inference::default()
+    };
+    assert!(matches!(
+        inference.global(&games),
+        Err(InferError::BagsTooLarge { colours: 1, .. })
+    ));
+}
+
+fn game() -> impl Strategy<Value = Game> {
+    let round =
+        prop::collection::btree_map("[a-z]{1,8}", 0..100_u32, 1..5).prop_map(|cubes| Round {
+            cubes: cubes
+                .into_iter()
+                .map(|(colour, count)| (Colour(colour), count))
+                .collect(),
+        });
+    (any::<u32>(), prop::collection::vec(round, 1..6))
+        .prop_map(|(id, rounds)| Game::new(id, rounds).unwrap())
+}
+
+proptest! {
+    #[test]
+    fn test_round_trip(game in game()) {
+        let record = game.to_string();
+        prop_assert_eq!(&Game::parse(&record, &mut Diagnostics::default()).unwrap(), &game);
+        prop_assert_eq!(&serde_json::from_str::<Game>(&to_json(&game)).unwrap(), &game);
+        prop_assert_eq!(
+            csv_rows(&game).count(),
+            game.rounds().iter().map(|round| round.cubes.iter().count()).sum::<usize>()
+        );
+    }
+}
+
+#[test]
+fn test_invalid_game() {
+    let round = |colour: &str| Round {
+        cubes: [(Colour::from(colour), 1)].into_iter().collect(),
+    };
+
+    assert_eq!(Game::new(1, vec![]), Err(GameError::NoRounds));
+    assert_eq!(
+        Game::new(1, vec![round("red"), Round::default()]),
+        Err(GameError::EmptyRound(2))
+    );
+    assert_eq!(
+        Game::new(1, vec![round("light red")]),
+        Err(GameError::InvalidColour(Colour::from("light red")))
+    );
+    assert!(serde_json::from_str::<Game>(r#"{"id":1,"rounds":[{}]}"#).is_err());
+}
+
+#[test]
+fn test_repeated_colour() {
+    let contents = "Game 1: 3 red, 2 blue, 3 red; 1 red\n";
+    let games = parse_games(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(games[0].rounds()[0].cubes.get("red"), 6);
+    assert_eq!(games[0].total_cubes(), 9);
+
+    let error = parse_games(contents, &mut Diagnostics::new(Mode::Strict)).unwrap_err();
+    assert_eq!(&contents[error.span.start..error.span.end], "3 red");
+    assert_eq!(error.span.line_column(contents), (1, 24));
+}
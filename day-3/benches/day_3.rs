@@ -0,0 +1,120 @@
+use aoc_common::Diagnostics;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_3::{sum_gear_ratios, sum_part_numbers, Grid, NumberIndex, Token, TokenKind};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+/// Schematic made of the example tiled `times` × `times`. Every example line ends with `.`, so
+/// numbers of neighbouring tiles never merge.
+fn synthetic(times: usize) -> String {
+    let rows = EXAMPLE
+        .lines()
+        .map(|line| line.repeat(times))
+        .collect::<Vec<_>>()
+        .join("\n");
+    (0..times).map(|_| format!("{rows}\n")).collect()
+}
+
+/// Sums part numbers comparing every number against every symbol, the way it was done before
+/// [`NumberIndex`], to show what the index saves.
+fn pairwise_part_numbers(grid: &Grid) -> i64 {
+    let (numbers, symbols): (Vec<Token>, Vec<Token>) = grid
+        .tokens()
+        .partition(|token| token.kind == TokenKind::Number);
+
+    numbers
+        .iter()
+        .filter(|number| {
+            symbols.iter().any(|symbol| {
+                grid.around(number.row, number.cols.clone())
+                    .any(|cell| cell == (symbol.row, symbol.cols.start))
+            })
+        })
+        .filter_map(|number| number.text.parse::<i64>().ok())
+        .sum()
+}
+
+/// Sums part numbers with [`NumberIndex`], without parsing diagnostics around it.
+fn indexed_part_numbers(grid: &Grid) -> i64 {
+    let (numbers, symbols): (Vec<Token>, Vec<Token>) = grid
+        .tokens()
+        .partition(|token| token.kind == TokenKind::Number);
+    let index = NumberIndex::new(grid, &numbers);
+
+    let mut parts = vec![false; numbers.len()];
+    for symbol in &symbols {
+        for number in index.around(grid, symbol) {
+            parts[number] = true;
+        }
+    }
+
+    numbers
+        .iter()
+        .zip(parts)
+        .filter(|(_, part)| *part)
+        .filter_map(|(number, _)| number.text.parse::<i64>().ok())
+        .sum()
+}
+
+/// Adjacency of numbers and symbols found pairwise and with the index, on growing schematics. The
+/// pairwise time grows with the square of the cell count, the indexed one linearly.
+fn adjacency(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-3/adjacency");
+    group.sample_size(10);
+
+    for times in [7, 14, 28] {
+        let input = synthetic(times);
+        let grid = Grid::new(&input);
+        let name = format!("{0}x{0}", times * 10);
+        group.bench_with_input(BenchmarkId::new("pairwise", &name), &grid, |b, grid| {
+            b.iter(|| pairwise_part_numbers(black_box(grid)));
+        });
+        group.bench_with_input(BenchmarkId::new("indexed", &name), &grid, |b, grid| {
+            b.iter(|| indexed_part_numbers(black_box(grid)));
+        });
+    }
+
+    // Too large to be compared pairwise in any reasonable time.
+    let input = synthetic(1000);
+    let grid = Grid::new(&input);
+    group.bench_with_input(
+        BenchmarkId::new("indexed", "10000x10000"),
+        &grid,
+        |b, grid| {
+            b.iter(|| indexed_part_numbers(black_box(grid)));
+        },
+    );
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    // 140 × 140 schematic has the size of a real puzzle input.
+    let inputs = [
+        ("example", EXAMPLE.to_owned()),
+        ("synthetic", synthetic(14)),
+    ];
+    let mut group = c.benchmark_group("day-3");
+
+    for (name, input) in &inputs {
+        group.bench_with_input(
+            BenchmarkId::new("sum_part_numbers", name),
+            input,
+            |b, input| {
+                b.iter(|| sum_part_numbers(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("sum_gear_ratios", name),
+            input,
+            |b, input| {
+                b.iter(|| sum_gear_ratios(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, solvers, adjacency);
+criterion_main!(benches);
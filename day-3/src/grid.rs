@@ -0,0 +1,148 @@
+use std::ops::{Index, Range};
+
+use aoc_common::Span;
+
+/// Rows of a text grid addressed by `(row, col)` byte coordinates.
+///
+/// Rows are split with [`str::lines`], so `\r\n` terminators are fine and rows may differ in
+/// length. Cells past the end of a row don't exist, so nothing is ever adjacent across row ends.
+#[derive(Clone, Debug)]
+pub struct Grid<'a> {
+    rows: Vec<&'a str>,
+    /// Byte offset of every row into the whole text.
+    offsets: Vec<usize>,
+}
+
+/// Run of cells within a single row of the grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub row: usize,
+    pub cols: Range<usize>,
+    pub text: &'a str,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Run of ASCII digits.
+    Number,
+    /// Single character which is neither a digit nor `.`.
+    Symbol,
+}
+
+impl<'a> Grid<'a> {
+    #[must_use]
+    pub fn new(contents: &'a str) -> Self {
+        let rows = contents.lines().collect::<Vec<_>>();
+        let offsets = rows
+            .iter()
+            .map(|row| Span::of(contents, row).start)
+            .collect();
+
+        Self { rows, offsets }
+    }
+
+    /// Number of rows.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Length of the row in bytes, 0 for rows past the last one.
+    #[must_use]
+    pub fn width(&self, row: usize) -> usize {
+        self.rows.get(row).map_or(0, |row| row.len())
+    }
+
+    /// Byte at the cell, if the cell exists.
+    #[must_use]
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<u8> {
+        self.rows
+            .get(row)
+            .and_then(|row| row.as_bytes().get(col))
+            .copied()
+    }
+
+    /// Existing cells out of 8 around the cell.
+    pub fn neighbours(
+        &self,
+        (row, col): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.around(row, col..col + 1)
+    }
+
+    /// Existing cells around a run of cells within the row, i.e. the ring surrounding it.
+    pub fn around(
+        &self,
+        row: usize,
+        cols: Range<usize>,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let left = cols.start.checked_sub(1);
+        let right = cols.end;
+        let above = row.checked_sub(1).into_iter().flat_map(move |above| {
+            (cols.start.saturating_sub(1)..=right).map(move |col| (above, col))
+        });
+        let below = (cols.start.saturating_sub(1)..=right).map(move |col| (row + 1, col));
+        let sides = left
+            .map(|left| (row, left))
+            .into_iter()
+            .chain([(row, right)]);
+
+        above
+            .chain(sides)
+            .chain(below)
+            .filter(|&(row, col)| col < self.width(row))
+    }
+
+    /// Numbers and symbols of every row, in reading order. Characters other than ASCII digits and
+    /// `.` are symbols of a single character each.
+    pub fn tokens(&self) -> impl Iterator<Item = Token<'a>> + '_ {
+        self.rows.iter().enumerate().flat_map(|(row, &text)| {
+            let mut chars = text.char_indices().peekable();
+            std::iter::from_fn(move || loop {
+                let (start, c) = chars.next()?;
+                if c == '.' {
+                    continue;
+                }
+                if !c.is_ascii_digit() {
+                    let end = start + c.len_utf8();
+                    return Some(Token {
+                        kind: TokenKind::Symbol,
+                        row,
+                        cols: start..end,
+                        text: &text[start..end],
+                    });
+                }
+
+                let mut end = start + 1;
+                while chars.next_if(|(_, c)| c.is_ascii_digit()).is_some() {
+                    end += 1;
+                }
+                return Some(Token {
+                    kind: TokenKind::Number,
+                    row,
+                    cols: start..end,
+                    text: &text[start..end],
+                });
+            })
+        })
+    }
+
+    /// Span of a run of cells within the row, as byte offsets into the whole text.
+    #[must_use]
+    pub fn span(&self, row: usize, cols: &Range<usize>) -> Span {
+        let offset = self.offsets.get(row).copied().unwrap_or_default();
+        Span::new(offset + cols.start, offset + cols.end)
+    }
+}
+
+impl Index<(usize, usize)> for Grid<'_> {
+    type Output = u8;
+
+    /// # Panics
+    ///
+    /// Panics if the cell doesn't exist.
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.rows[row].as_bytes()[col]
+    }
+}
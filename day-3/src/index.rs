@@ -0,0 +1,55 @@
+use crate::{Grid, Token};
+
+/// Marks cells outside of any number.
+const NONE: u32 = u32::MAX;
+
+/// Map from every cell of the grid to the number occupying it, so that numbers around a symbol are
+/// found by looking at its neighbours only. Building and querying the whole grid takes linear time.
+#[derive(Clone, Debug)]
+pub struct NumberIndex {
+    /// Index of the number at every cell of every row, [`NONE`] for cells outside of numbers. Four
+    /// bytes a cell keep the map of a 10k × 10k grid within 400 MB.
+    owners: Vec<Vec<u32>>,
+}
+
+impl NumberIndex {
+    /// Index of numbers of the grid, every one identified by its position in `numbers`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are `u32::MAX` numbers or more, or any of them lies outside of the grid.
+    #[must_use]
+    pub fn new<'a>(grid: &Grid, numbers: impl IntoIterator<Item = &'a Token<'a>>) -> Self {
+        let mut owners = (0..grid.height())
+            .map(|row| vec![NONE; grid.width(row)])
+            .collect::<Vec<_>>();
+        for (index, number) in numbers.into_iter().enumerate() {
+            let index = u32::try_from(index)
+                .ok()
+                .filter(|&index| index != NONE)
+                .expect("Too many numbers");
+            owners[number.row][number.cols.clone()].fill(index);
+        }
+
+        Self { owners }
+    }
+
+    /// Number occupying the cell, if any.
+    #[must_use]
+    pub fn owner(&self, (row, col): (usize, usize)) -> Option<usize> {
+        let owner = *self.owners.get(row)?.get(col)?;
+        (owner != NONE).then_some(owner as usize)
+    }
+
+    /// Distinct numbers in cells around the token, in reading order.
+    #[must_use]
+    pub fn around(&self, grid: &Grid, token: &Token) -> Vec<usize> {
+        let mut numbers = grid
+            .around(token.row, token.cols.clone())
+            .filter_map(|cell| self.owner(cell))
+            .collect::<Vec<_>>();
+        numbers.sort_unstable();
+        numbers.dedup();
+        numbers
+    }
+}
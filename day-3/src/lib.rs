@@ -0,0 +1,129 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+//! Day 3: Gear Ratios.
+//!
+//! Engine schematic is a grid of numbers and symbols. Part numbers are the ones adjacent to any symbol.
+
+mod grid;
+mod index;
+mod query;
+
+use aoc_common::{Answer, Diagnostics, ParseError, Solver};
+
+pub use grid::{Grid, Token, TokenKind};
+pub use index::NumberIndex;
+pub use query::{Group, Query, Reduce, Report};
+
+/// Solver of day 3.
+pub struct Day3;
+
+impl Solver for Day3 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        sum_part_numbers(input, diagnostics).map(Into::into)
+    }
+
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        sum_gear_ratios(input, diagnostics).map(Into::into)
+    }
+}
+
+/// Sums gear ratios of every `*` symbol adjacent to exactly two part numbers, see [`Query::gears`].
+///
+/// # Errors
+///
+/// Fails when any number doesn't fit into `i64` or schematic has a character which is neither digit,
+/// `.` nor punctuation symbol. Lenient mode drops such numbers and characters instead.
+pub fn sum_gear_ratios(contents: &str, diagnostics: &mut Diagnostics) -> Result<i64, ParseError> {
+    Query::gears()
+        .run(contents, diagnostics)
+        .map(|report| report.total)
+}
+
+/// Sums all numbers adjacent to any symbol.
+///
+/// # Errors
+///
+/// Fails when any number doesn't fit into `i64` or schematic has a character which is neither digit,
+/// `.` nor punctuation symbol. Lenient mode drops such numbers and characters instead. Also fails
+/// when the sum doesn't fit into `i64`.
+pub fn sum_part_numbers(contents: &str, diagnostics: &mut Diagnostics) -> Result<i64, ParseError> {
+    let schematic = Schematic::scan(contents, diagnostics)?;
+    let index = schematic.index();
+
+    let mut parts = vec![false; schematic.numbers.len()];
+    for symbol in &schematic.symbols {
+        for number in index.around(&schematic.grid, symbol) {
+            parts[number] = true;
+        }
+    }
+
+    schematic
+        .numbers
+        .iter()
+        .zip(parts)
+        .filter(|(_, part)| *part)
+        .try_fold(0_i64, |sum, ((number, value), _)| {
+            sum.checked_add(*value).ok_or_else(|| {
+                ParseError::new(
+                    schematic.grid.span(number.row, &number.cols),
+                    "sum of part numbers overflows",
+                )
+            })
+        })
+}
+
+/// Numbers and symbols of the schematic grid.
+struct Schematic<'a> {
+    grid: Grid<'a>,
+    numbers: Vec<(Token<'a>, i64)>,
+    symbols: Vec<Token<'a>>,
+}
+
+impl<'a> Schematic<'a> {
+    fn index(&self) -> NumberIndex {
+        NumberIndex::new(&self.grid, self.numbers.iter().map(|(number, _)| number))
+    }
+
+    fn scan(contents: &'a str, diagnostics: &mut Diagnostics) -> Result<Self, ParseError> {
+        let grid = Grid::new(contents);
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+
+        for token in grid.tokens() {
+            let span = grid.span(token.row, &token.cols);
+            match token.kind {
+                TokenKind::Number => {
+                    let value = token.text.parse::<i64>().map_err(|_| {
+                        ParseError::new(span, format!("number `{}` is too large", token.text))
+                    });
+                    numbers.extend(diagnostics.record(value)?.map(|value| (token, value)));
+                }
+                TokenKind::Symbol => {
+                    let symbol = if token.text.chars().all(|c| c.is_ascii_punctuation()) {
+                        Ok(token)
+                    } else {
+                        let message = format!(
+                            "unexpected character `{}` in schematic",
+                            token.text.escape_debug()
+                        );
+                        Err(ParseError::new(span, message))
+                    };
+                    symbols.extend(diagnostics.record(symbol)?);
+                }
+            }
+        }
+
+        Ok(Self {
+            grid,
+            numbers,
+            symbols,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -1,135 +1,101 @@
 #![warn(clippy::all)]
 #![warn(clippy::pedantic)]
-use std::path::PathBuf;
 
-use clap::{ArgAction, Parser};
-use itertools::Itertools;
-use regex::Regex;
-use tracing::{error, Level};
-use tracing_subscriber::FmtSubscriber;
+use std::process::ExitCode;
 
-fn main() {
-    let args = Args::parse();
-
-    let log_level = match args.verbose {
-        0 => Level::ERROR,
-        1 => Level::WARN,
-        2 => Level::INFO,
-        3 => Level::DEBUG,
-        _ => Level::TRACE,
-    };
+use aoc_common::{csv_row, json_line, report_dropped, CommonArgs, Diagnostics, Format};
+use clap::Parser;
+use day_3::{Day3, Query, Reduce};
+use tracing::error;
 
-    let subscriber = FmtSubscriber::builder().with_max_level(log_level).finish();
-    if let Err(error) = tracing::subscriber::set_global_default(subscriber) {
-        eprintln!("[ERROR] Cannot set up logging.");
-        eprintln!("Error cause: {error}");
-    };
+fn main() -> ExitCode {
+    let args = Args::parse();
+    args.common.init_logging();
 
-    if args.filename.is_none() {
-        error!("Inputs other than file are currently not supported.");
-        return;
+    if args.symbol.is_some() || args.arity.is_some() || args.reduce.is_some() {
+        return query(&args);
     }
 
-    let Ok(contents) = std::fs::read_to_string(args.filename.as_ref().unwrap()) else {
-        error!("Cannot read file contents.");
-        return;
-    };
-
-    // Part I
-    sum_part_numbers(contents.as_str());
-
-    // Part II
-    sum_gear_ratios(contents.as_str());
+    aoc_common::run(&Day3, &args.common)
 }
 
-fn sum_gear_ratios(contents: &str) {
-    // We have to add 1, because lines() removes new line character from &str.
-    let line_length = contents.lines().take(1).map(str::len).sum::<usize>() + 1;
-
-    let numbers = Regex::new(r"(\d+)").expect("Invalid pattern");
-    let symbols = Regex::new(r"([^\d\.\n\r])").expect("Invalid symbol pattern");
-
-    let numbers = numbers
-        .captures_iter(contents)
-        .filter_map(|captures| captures.get(0))
-        .map(|element| element.range())
-        .collect::<Vec<_>>();
-    let symbols = symbols
-        .captures_iter(contents)
-        .filter_map(|captures| captures.get(0))
-        .filter(|symbol| symbol.as_str() == "*")
-        .enumerate()
-        .map(|(index, element)| (index, element.range()))
-        .collect::<Vec<_>>();
-
-    let sum = symbols.into_iter().map(|(_, symbol)| {
-        numbers.iter().filter(|number|{
-            number.start.saturating_sub(1 + line_length) <= symbol.start && number.end.saturating_sub(line_length - 1) >= symbol.end ||
-            // Check current line for symbol.
-            number.start.saturating_sub(1) <= symbol.start && number.end.saturating_add(1) >= symbol.end ||
-            // Check next line for symbol.
-            number.start.saturating_add(line_length - 1) <= symbol.start && number.end.saturating_add(line_length + 1) >= symbol.end
-        })
-        .unique()
-        .cloned()
-        .collect::<Vec<_>>()
-    })
-    .filter(|group| group.len() == 2)
-    .map(|group| group.into_iter().filter_map(|number| contents[number].parse::<i64>().ok()).product::<i64>())
-    .sum::<i64>();
-
-    println!("{sum}");
-}
-
-fn sum_part_numbers(contents: &str) {
-    // We have to add 1, because lines() removes new line character from &str.
-    let line_length = contents.lines().take(1).map(str::len).sum::<usize>() + 1;
-
-    let numbers = Regex::new(r"(\d+)").expect("Invalid pattern");
-    let symbols = Regex::new(r"([^\d\.\n\r])").expect("Invalid symbol pattern");
-
-    let mut numbers = numbers
-        .captures_iter(contents)
-        .filter_map(|captures| captures.get(0))
-        .map(|element| element.range())
-        .collect::<Vec<_>>();
-    let symbols = symbols
-        .captures_iter(contents)
-        .filter_map(|captures| captures.get(0))
-        .map(|element| element.range())
-        .collect::<Vec<_>>();
-
-    numbers.retain(|number| {
-            symbols
-                .iter()
-                .any(|symbol| {
-                    // Check previous line for symbol.
-                    number.start.saturating_sub(1 + line_length) <= symbol.start && number.end.saturating_sub(line_length - 1) >= symbol.end ||
-                    // Check current line for symbol.
-                    number.start.saturating_sub(1) <= symbol.start && number.end.saturating_add(1) >= symbol.end ||
-                    // Check next line for symbol.
-                    number.start.saturating_add(line_length - 1) <= symbol.start && number.end.saturating_add(line_length + 1) >= symbol.end
-                })
-        });
+/// Prints groups of numbers around symbols selected on command line instead of solving the puzzle.
+fn query(args: &Args) -> ExitCode {
+    let inputs = match args.common.read_inputs() {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            error!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let query = Query {
+        symbol: args.symbol,
+        arity: args.arity,
+        reduce: args.reduce.unwrap_or_default(),
+    };
 
-    let sum = numbers
-        .into_iter()
-        .filter_map(|range| contents[range].parse::<i64>().ok())
-        .sum::<i64>();
+    let format = args.common.format;
+    if format == Format::Csv {
+        let header = csv_row(&[&"input", &"symbol", &"row", &"col", &"numbers", &"value"]);
+        println!("{header}");
+    }
+    for input in inputs {
+        let mut diagnostics = Diagnostics::new(args.common.mode());
+        let report = query.run(&input.contents, &mut diagnostics);
+        report_dropped(&input, &diagnostics);
+        let report = match report {
+            Ok(report) => report,
+            Err(error) => {
+                eprintln!("{}", error.render(&input.name(), &input.contents));
+                return ExitCode::FAILURE;
+            }
+        };
+
+        match format {
+            Format::Text => {
+                if let Some(header) = input.header() {
+                    println!("{header}");
+                }
+                print!("{report}");
+            }
+            Format::Json => println!("{}", json_line(&report)),
+            Format::Csv => {
+                for group in &report.groups {
+                    let numbers = group
+                        .numbers
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(";");
+                    let row = csv_row(&[
+                        &input.name(),
+                        &group.symbol,
+                        &group.row,
+                        &group.col,
+                        &numbers,
+                        &group.value,
+                    ]);
+                    println!("{row}");
+                }
+            }
+        }
+    }
 
-    println!("{sum}");
+    ExitCode::SUCCESS
 }
 
 #[derive(Parser)]
 struct Args {
-    #[arg(short, long, value_name = "<FILE>")]
-    filename: Option<PathBuf>,
-    #[arg(short, long, value_name = "<COUNT>", action = ArgAction::Set, default_value_t = 0)]
-    greens: u32,
-    #[arg(short, long, value_name = "<COUNT>", action = ArgAction::Set, default_value_t = 0)]
-    blues: u32,
-    #[arg(short, long, value_name = "<COUNT>", action = ArgAction::Set, default_value_t = 0)]
-    reds: u32,
-    #[arg(short, long, action = ArgAction::Count)]
-    verbose: u8,
+    #[command(flatten)]
+    common: CommonArgs,
+    /// Instead of solving, report numbers around every symbol of this character, or of any
+    /// character if only `--arity` or `--reduce` is given.
+    #[arg(long, value_name = "<CHAR>", conflicts_with_all = ["check", "record"])]
+    symbol: Option<char>,
+    /// Report only symbols with exactly this many numbers around, instead of any non-zero count.
+    #[arg(long, value_name = "<COUNT>", conflicts_with_all = ["check", "record"])]
+    arity: Option<usize>,
+    /// Operation reducing numbers around every reported symbol to a single value [default: product].
+    #[arg(long, value_name = "<OPERATION>", conflicts_with_all = ["check", "record"])]
+    reduce: Option<Reduce>,
 }
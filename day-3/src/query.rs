@@ -0,0 +1,179 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use aoc_common::{Diagnostics, ParseError};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::Schematic;
+
+/// Selects symbols by their character and count of numbers around them, and reduces numbers around
+/// every selected symbol to a single value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Query {
+    /// Symbol to select, any symbol if `None`.
+    pub symbol: Option<char>,
+    /// Exact count of numbers around selected symbols, any non-zero count if `None`.
+    pub arity: Option<usize>,
+    pub reduce: Reduce,
+}
+
+/// Operation reducing numbers around a symbol to a single value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Reduce {
+    /// Product of the numbers, 1 for no numbers.
+    #[default]
+    Product,
+    /// Sum of the numbers, 0 for no numbers.
+    Sum,
+    /// Largest of the numbers, 0 for no numbers.
+    Max,
+}
+
+/// Selected symbol together with numbers around it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Group {
+    pub symbol: char,
+    /// 1-based row and column of the symbol.
+    pub row: usize,
+    pub col: usize,
+    /// Numbers around the symbol, in reading order.
+    pub numbers: Vec<i64>,
+    /// Numbers reduced with the operation of the query.
+    pub value: i64,
+}
+
+/// Answer to a [`Query`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub groups: Vec<Group>,
+    /// Sum of values of all groups.
+    pub total: i64,
+    /// Count of symbols by count of numbers around them, for every symbol selected by its character
+    /// regardless of arity.
+    pub histogram: BTreeMap<char, BTreeMap<usize, usize>>,
+}
+
+impl Query {
+    /// Query of part two: `*` symbols with exactly two numbers around, multiplied.
+    #[must_use]
+    pub fn gears() -> Self {
+        Self {
+            symbol: Some('*'),
+            arity: Some(2),
+            reduce: Reduce::Product,
+        }
+    }
+
+    /// Runs the query over the schematic.
+    ///
+    /// # Errors
+    ///
+    /// Fails when any number doesn't fit into `i64` or schematic has a character which is neither
+    /// digit, `.` nor punctuation symbol. Lenient mode drops such numbers and characters instead.
+    /// Also fails when value of a group or the total doesn't fit into `i64`.
+    pub fn run(&self, contents: &str, diagnostics: &mut Diagnostics) -> Result<Report, ParseError> {
+        let schematic = Schematic::scan(contents, diagnostics)?;
+        let index = schematic.index();
+        let mut report = Report::default();
+
+        for token in &schematic.symbols {
+            // Symbols are single characters.
+            let symbol = token.text.chars().next().unwrap_or_default();
+            if self.symbol.is_some_and(|selected| selected != symbol) {
+                continue;
+            }
+
+            let numbers = index.around(&schematic.grid, token);
+            *report
+                .histogram
+                .entry(symbol)
+                .or_default()
+                .entry(numbers.len())
+                .or_default() += 1;
+            if self
+                .arity
+                .map_or(numbers.is_empty(), |arity| arity != numbers.len())
+            {
+                continue;
+            }
+
+            let numbers = numbers
+                .into_iter()
+                .map(|number| schematic.numbers[number].1)
+                .collect::<Vec<_>>();
+            let overflow = || {
+                ParseError::new(
+                    schematic.grid.span(token.row, &token.cols),
+                    format!("{:?} of numbers around `{symbol}` overflows", self.reduce),
+                )
+            };
+            let value = self.reduce.apply(&numbers).ok_or_else(overflow)?;
+            report.total = report.total.checked_add(value).ok_or_else(overflow)?;
+            report.groups.push(Group {
+                symbol,
+                row: token.row + 1,
+                col: token.cols.start + 1,
+                numbers,
+                value,
+            });
+        }
+
+        Ok(report)
+    }
+}
+
+impl Reduce {
+    /// Reduces the numbers with the operation, `None` on overflow.
+    #[must_use]
+    pub fn apply(self, numbers: &[i64]) -> Option<i64> {
+        match self {
+            Reduce::Product => numbers.iter().try_fold(1_i64, |u, &v| u.checked_mul(v)),
+            Reduce::Sum => numbers.iter().try_fold(0_i64, |u, &v| u.checked_add(v)),
+            Reduce::Max => Some(numbers.iter().copied().max().unwrap_or_default()),
+        }
+    }
+}
+
+impl Display for Group {
+    /// Renders the group as a single line, e.g. `` `*` at 2:4 with 467, 35: 16345 ``.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` at {}:{} with ", self.symbol, self.row, self.col)?;
+        for (index, number) in self.numbers.iter().enumerate() {
+            let separator = if index > 0 { ", " } else { "" };
+            write!(f, "{separator}{number}")?;
+        }
+        write!(f, ": {}", self.value)
+    }
+}
+
+impl Display for Report {
+    /// Renders every group on its own line followed by the total and the histogram, e.g.
+    ///
+    /// ```text
+    /// `*` at 2:4 with 467, 35: 16345
+    /// total: 16345
+    /// `*`: 1 symbol(s) with 1 number(s), 1 symbol(s) with 2 number(s)
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for group in &self.groups {
+            writeln!(f, "{group}")?;
+        }
+        writeln!(f, "total: {}", self.total)?;
+        for (symbol, counts) in &self.histogram {
+            write!(f, "`{symbol}`:")?;
+            for (index, (numbers, symbols)) in counts.iter().enumerate() {
+                let separator = if index > 0 { "," } else { "" };
+                write!(
+                    f,
+                    "{separator} {symbols} symbol(s) with {numbers} number(s)"
+                )?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use super::*;
+
+#[test]
+fn test_sum_part_numbers() {
+    let contents = include_str!("../test.txt");
+    let sum = sum_part_numbers(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(4361, sum);
+
+    let contents = "9000000000000000000#9000000000000000000\n";
+    let error = sum_part_numbers(contents, &mut Diagnostics::default()).unwrap_err();
+    assert_eq!(error.span.line_column(contents), (1, 21));
+}
+
+#[test]
+fn test_sum_gear_ratios() {
+    let contents = include_str!("../test.txt");
+    let sum = sum_gear_ratios(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(467_835, sum);
+}
+
+#[test]
+fn test_crlf_and_ragged_rows() {
+    let crlf = include_str!("../test.txt").replace('\n', "\r\n");
+    assert_eq!(
+        sum_part_numbers(&crlf, &mut Diagnostics::default()).unwrap(),
+        4361
+    );
+    assert_eq!(
+        sum_gear_ratios(&crlf, &mut Diagnostics::default()).unwrap(),
+        467_835
+    );
+
+    // `#` ends the first row right above the end of `12`, `*` starts the last row, which would
+    // touch the end of `34` in the row above if rows wrapped around.
+    let ragged = "..#\n.12\n....34\n*..\n";
+    assert_eq!(
+        sum_part_numbers(ragged, &mut Diagnostics::default()).unwrap(),
+        12
+    );
+}
+
+#[test]
+fn test_grid() {
+    let grid = Grid::new("467..\n...*\n..35.");
+
+    assert_eq!((grid.height(), grid.width(1)), (3, 4));
+    assert_eq!(grid[(1, 3)], b'*');
+    assert_eq!(grid.get((1, 4)), None);
+    assert_eq!(
+        grid.neighbours((0, 0)).collect::<Vec<_>>(),
+        vec![(0, 1), (1, 0), (1, 1)]
+    );
+    assert_eq!(
+        grid.neighbours((2, 4)).collect::<Vec<_>>(),
+        vec![(1, 3), (2, 3)]
+    );
+    assert_eq!(
+        grid.tokens()
+            .map(|token| (token.kind, token.row, token.cols, token.text))
+            .collect::<Vec<_>>(),
+        vec![
+            (TokenKind::Number, 0, 0..3, "467"),
+            (TokenKind::Symbol, 1, 3..4, "*"),
+            (TokenKind::Number, 2, 2..4, "35"),
+        ]
+    );
+}
+
+#[test]
+fn test_query() {
+    let contents = include_str!("../test.txt");
+    let query = Query {
+        symbol: None,
+        arity: Some(1),
+        reduce: Reduce::Max,
+    };
+    let report = query.run(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(
+        report
+            .groups
+            .iter()
+            .map(|group| (group.symbol, group.row, group.col, group.value))
+            .collect::<Vec<_>>(),
+        vec![
+            ('#', 4, 7, 633),
+            ('*', 5, 4, 617),
+            ('+', 6, 6, 592),
+            ('$', 9, 4, 664)
+        ]
+    );
+    assert_eq!(report.total, 633 + 617 + 592 + 664);
+    assert_eq!(report.histogram[&'*'], BTreeMap::from([(1, 1), (2, 2)]));
+
+    let gears = Query {
+        reduce: Reduce::Sum,
+        ..Query::gears()
+    };
+    assert_eq!(
+        gears
+            .run(contents, &mut Diagnostics::default())
+            .unwrap()
+            .groups[0]
+            .to_string(),
+        "`*` at 2:4 with 467, 35: 502"
+    );
+
+    let contents = "9999999.9999999\n.......*.......\n......9999999..\n";
+    let query = Query {
+        arity: Some(3),
+        ..Query::gears()
+    };
+    let error = query
+        .run(contents, &mut Diagnostics::default())
+        .unwrap_err();
+    assert_eq!(error.span.line_column(contents), (2, 8));
+}
+
+#[test]
+fn test_number_index() {
+    let grid = Grid::new(include_str!("../test.txt"));
+    let (numbers, symbols): (Vec<_>, Vec<_>) = grid
+        .tokens()
+        .partition(|token| token.kind == TokenKind::Number);
+    let index = NumberIndex::new(&grid, &numbers);
+
+    // Numbers are identified by their position in reading order: 467, 114, 35, 633, 617, ...
+    assert_eq!(index.owner((0, 0)), Some(0));
+    assert_eq!(index.owner((0, 2)), Some(0));
+    assert_eq!(index.owner((2, 3)), Some(2));
+    assert_eq!(index.owner((9, 7)), Some(9));
+    assert_eq!(index.owner((0, 3)), None);
+    assert_eq!(index.owner((10, 0)), None);
+
+    // Both cells of `35` touch the first `*`, yet it is listed once.
+    assert_eq!(index.around(&grid, &symbols[0]), vec![0, 2]);
+    assert_eq!(index.around(&grid, &symbols[1]), vec![3]);
+    assert_eq!(index.around(&grid, &symbols[5]), vec![7, 9]);
+
+    // `467` touches both symbols.
+    let grid = Grid::new("467*\n...$\n");
+    let (numbers, symbols): (Vec<_>, Vec<_>) = grid
+        .tokens()
+        .partition(|token| token.kind == TokenKind::Number);
+    let index = NumberIndex::new(&grid, &numbers);
+    assert_eq!(index.around(&grid, &symbols[0]), vec![0]);
+    assert_eq!(index.around(&grid, &symbols[1]), vec![0]);
+}
@@ -0,0 +1,101 @@
+use aoc_common::Diagnostics;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_4::{
+    count_total_cards, create_replication_table, total_cards_score, winning_numbers_count,
+};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+/// Cards with 10 winning and 25 owned numbers, as in real puzzle input.
+///
+/// Only few cards have any matches, so the number of won copies stays bounded like in real input.
+fn synthetic(cards: usize) -> String {
+    const MATCHES: [usize; 10] = [0, 0, 1, 0, 2, 0, 0, 1, 0, 0];
+
+    (1..=cards)
+        .map(|id| {
+            let base = id % 100;
+            let winning = (0..10).map(|k| (base + k * 5) % 100).collect::<Vec<_>>();
+            let owned = winning
+                .iter()
+                .copied()
+                .take(MATCHES[id % 10])
+                .chain((0..).map(|k| (base + 1 + (k % 20) * 5 + k / 20) % 100))
+                .take(25);
+
+            format!(
+                "Card {id:>4}: {} | {}\n",
+                numbers(winning.iter().copied()),
+                numbers(owned)
+            )
+        })
+        .collect()
+}
+
+fn numbers(numbers: impl Iterator<Item = usize>) -> String {
+    numbers
+        .map(|number| format!("{number:>2}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn inputs() -> [(&'static str, String); 2] {
+    [
+        ("example", EXAMPLE.to_owned()),
+        ("synthetic", synthetic(5_000)),
+    ]
+}
+
+fn parsers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-4/parse");
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(
+            BenchmarkId::new("winning_numbers_count", name),
+            input,
+            |b, input| {
+                b.iter(|| {
+                    black_box(input)
+                        .lines()
+                        .map(winning_numbers_count)
+                        .sum::<Result<usize, _>>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("create_replication_table", name),
+            input,
+            |b, input| {
+                b.iter(|| create_replication_table(black_box(input), &mut Diagnostics::default()))
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-4");
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(
+            BenchmarkId::new("total_cards_score", name),
+            input,
+            |b, input| {
+                b.iter(|| total_cards_score(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("count_total_cards", name),
+            input,
+            |b, input| {
+                b.iter(|| count_total_cards(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parsers, solvers);
+criterion_main!(benches);
@@ -0,0 +1,162 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+//! Day 4: Scratchcards.
+//!
+//! Every card lists winning numbers and numbers owned. In part two winning cards win copies of the
+//! following cards instead of points.
+
+use aoc_common::{Answer, Diagnostics, ParseError, Solver};
+
+/// Solver of day 4.
+pub struct Day4;
+
+impl Solver for Day4 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        total_cards_score(input, diagnostics).map(Into::into)
+    }
+
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        count_total_cards(input, diagnostics).map(Into::into)
+    }
+}
+
+/// Sums points of all cards. First match is worth one point, every next one doubles the score.
+///
+/// # Errors
+///
+/// Fails on the first malformed card, unless in lenient mode which drops such cards.
+pub fn total_cards_score(cards: &str, diagnostics: &mut Diagnostics) -> Result<i32, ParseError> {
+    let point_score = |count: usize| if count == 0 { 0 } else { 1 << (count - 1) };
+
+    Ok(winning_numbers_counts(cards, diagnostics)?
+        .into_iter()
+        .map(point_score)
+        .sum())
+}
+
+/// Counts winning numbers of every well-formed card, skipping blank lines.
+fn winning_numbers_counts(
+    cards: &str,
+    diagnostics: &mut Diagnostics,
+) -> Result<Vec<usize>, ParseError> {
+    let mut counts = Vec::new();
+
+    for card in cards.lines() {
+        if card.trim().is_empty() {
+            diagnostics.ignore(ParseError::at(cards, card, "unexpected blank line"))?;
+            continue;
+        }
+
+        if let Some((label, _)) = card.split_once(':') {
+            let id = label.strip_prefix("Card").map(str::trim_start);
+            if id.is_none_or(|id| id.parse::<usize>().is_err()) {
+                diagnostics.ignore(ParseError::at(cards, label, "expected `Card <id>` label"))?;
+            }
+        }
+
+        let count = winning_numbers_count(card).map_err(|error| error.within(cards, card));
+        counts.extend(diagnostics.record(count)?);
+    }
+
+    Ok(counts)
+}
+
+/// Counts owned numbers of `Card <id>: <winning numbers> | <owned numbers>` record which are winning ones.
+///
+/// # Errors
+///
+/// Fails when any separator is missing or any of the numbers is not a number.
+pub fn winning_numbers_count(card: &str) -> Result<usize, ParseError> {
+    let Some((_, numbers)) = card.split_once(':') else {
+        return Err(ParseError::at(
+            card,
+            card,
+            "expected `Card <id>: <winning numbers> | <owned numbers>`",
+        ));
+    };
+    let Some((winning, owned)) = numbers.split_once('|') else {
+        return Err(ParseError::at(
+            card,
+            numbers,
+            "expected `|` between winning and owned numbers",
+        ));
+    };
+
+    let winning = parse_numbers(card, winning)?;
+    let owned = parse_numbers(card, owned)?;
+
+    Ok(owned
+        .iter()
+        .filter(|element| winning.contains(element))
+        .count())
+}
+
+fn parse_numbers(card: &str, numbers: &str) -> Result<Vec<i32>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|number| {
+            number
+                .parse::<i32>()
+                .map_err(|_| ParseError::at(card, number, format!("invalid number `{number}`")))
+        })
+        .collect()
+}
+
+/// Computes how many copies of each card are won, in order of cards.
+///
+/// # Errors
+///
+/// Fails on the first malformed card, unless in lenient mode which drops such cards.
+pub fn create_replication_table(
+    cards: &str,
+    diagnostics: &mut Diagnostics,
+) -> Result<Vec<Replication>, ParseError> {
+    let mut replication_table = winning_numbers_counts(cards, diagnostics)?
+        .into_iter()
+        .map(|score| Replication {
+            factor: 1,
+            record: score,
+        })
+        .collect::<Vec<_>>();
+
+    for index in 0..replication_table.len() {
+        let root = replication_table[index];
+        for replication in replication_table
+            .iter_mut()
+            .skip(index + 1)
+            .take(root.record)
+        {
+            replication.factor += root.factor;
+        }
+    }
+
+    Ok(replication_table)
+}
+
+/// Counts original cards and all copies won.
+///
+/// # Errors
+///
+/// Fails on the first malformed card, unless in lenient mode which drops such cards.
+pub fn count_total_cards(cards: &str, diagnostics: &mut Diagnostics) -> Result<usize, ParseError> {
+    Ok(create_replication_table(cards, diagnostics)?
+        .into_iter()
+        .map(|replication| replication.factor)
+        .sum::<usize>())
+}
+
+/// Number of instances of a single card together with count of its winning numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Replication {
+    /// Number of card instances, the original included.
+    pub factor: usize,
+    /// Number of winning numbers, i.e. how many following cards are copied by every instance.
+    pub record: usize,
+}
+
+#[cfg(test)]
+mod test;
@@ -4,7 +4,7 @@ use super::*;
 fn test_total_cards_score() {
     let contents = include_str!("../test.txt");
 
-    let total_score = contents.lines().map(winning_numbers_count).sum::<usize>();
+    let total_score = total_cards_score(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(13, total_score);
 }
@@ -13,7 +13,7 @@ fn test_total_cards_score() {
 fn test_scratchcards_count() {
     let contents = include_str!("../test.txt");
 
-    let cards_count = count_total_cards(contents);
+    let cards_count = count_total_cards(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(30, cards_count);
 }
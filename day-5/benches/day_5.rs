@@ -0,0 +1,85 @@
+use aoc_common::Diagnostics;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_5::{lowest_location, lowest_range_location, parse_seed_ranges, parse_seeds, seed_to_location};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Almanac with 10 seed ranges of `seeds` seeds in total and 40 entries in every map.
+///
+/// Every map permutes blocks of numbers, so each seed is mapped by a different chain of entries.
+fn synthetic(seeds: usize) -> String {
+    const ENTRIES: usize = 40;
+    const BLOCK: usize = 250_000;
+
+    let header = (0..10)
+        .map(|i| format!("{} {}", i * 900_000 + 12_345, seeds / 10))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let maps = MAPS.iter().enumerate().map(|(m, name)| {
+        let entries = (0..ENTRIES)
+            .map(|k| format!("{} {} {BLOCK}", ((k * 7 + m * 3) % ENTRIES) * BLOCK, k * BLOCK))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("{name} map:\n{entries}")
+    });
+
+    std::iter::once(format!("seeds: {header}"))
+        .chain(maps)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn inputs() -> [(&'static str, String); 2] {
+    [("example", EXAMPLE.to_owned()), ("synthetic", synthetic(100_000))]
+}
+
+fn parsers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-5/parse");
+
+    for (name, input) in &inputs() {
+        let (header, maps) = input.split_once("\n\n").unwrap();
+        group.bench_with_input(BenchmarkId::new("parse_seeds", name), header, |b, header| {
+            b.iter(|| parse_seeds(black_box(header)));
+        });
+        group.bench_with_input(BenchmarkId::new("parse_seed_ranges", name), header, |b, header| {
+            b.iter(|| parse_seed_ranges(black_box(header)));
+        });
+        group.bench_with_input(BenchmarkId::new("seed_to_location", name), maps, |b, maps| {
+            b.iter(|| {
+                seed_to_location(black_box(maps), &mut Diagnostics::default()).map(|mapping| mapping(black_box(79)))
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-5");
+    // Part two checks every seed of every range one by one.
+    group.sample_size(10);
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(BenchmarkId::new("lowest_location", name), input, |b, input| {
+            b.iter(|| lowest_location(black_box(input), &mut Diagnostics::default()));
+        });
+        group.bench_with_input(BenchmarkId::new("lowest_range_location", name), input, |b, input| {
+            b.iter(|| lowest_range_location(black_box(input), &mut Diagnostics::default()));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parsers, solvers);
+criterion_main!(benches);
@@ -0,0 +1,193 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+//! Day 5: If You Give A Seed A Fertilizer.
+//!
+//! Almanac maps every seed through a chain of category maps down to a location number.
+use std::collections::HashMap;
+use std::ops::Range;
+
+use aoc_common::{Answer, Diagnostics, ParseError, Solver};
+
+/// Solver of day 5.
+pub struct Day5;
+
+impl Solver for Day5 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        lowest_location(input, diagnostics).map(Into::into)
+    }
+
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        lowest_range_location(input, diagnostics).map(Into::into)
+    }
+}
+
+/// Categories every seed is mapped through, in order.
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Finds lowest location number corresponding to any of the initial seeds.
+///
+/// # Errors
+///
+/// Fails when almanac has no seeds, any of its maps is malformed or missing. Lenient mode drops
+/// malformed ranges of maps instead.
+pub fn lowest_location(contents: &str, diagnostics: &mut Diagnostics) -> Result<usize, ParseError> {
+    let (header, maps) = split_almanac(contents)?;
+    let seeds = parse_seeds(header).map_err(|error| error.within(contents, header))?;
+    let mapping = diagnostics.within(contents, maps, |diagnostics| seed_to_location(maps, diagnostics))?;
+
+    seeds
+        .into_iter()
+        .map(mapping)
+        .min()
+        .ok_or_else(|| ParseError::at(contents, header, "almanac has no seeds"))
+}
+
+/// Finds lowest location number corresponding to any seed from the initial seed ranges.
+///
+/// # Errors
+///
+/// Fails when almanac has no seeds, any of its maps is malformed or missing. Lenient mode drops
+/// malformed ranges of maps instead.
+pub fn lowest_range_location(contents: &str, diagnostics: &mut Diagnostics) -> Result<usize, ParseError> {
+    let (header, maps) = split_almanac(contents)?;
+    let seeds = parse_seed_ranges(header).map_err(|error| error.within(contents, header))?;
+    let mapping = diagnostics.within(contents, maps, |diagnostics| seed_to_location(maps, diagnostics))?;
+
+    seeds
+        .into_iter()
+        .flat_map(IntoIterator::into_iter)
+        .map(mapping)
+        .min()
+        .ok_or_else(|| ParseError::at(contents, header, "almanac has no seeds"))
+}
+
+/// Splits almanac into seeds header and maps following it.
+fn split_almanac(contents: &str) -> Result<(&str, &str), ParseError> {
+    contents
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end_of(contents, "expected maps after seeds header"))
+}
+
+/// Parses `seeds: <seed> <seed> ...` header of the almanac.
+///
+/// # Errors
+///
+/// Fails when header doesn't start with `seeds:` or any seed is not a number.
+pub fn parse_seeds(header: &str) -> Result<Vec<usize>, ParseError> {
+    let Some(seeds) = header.trim().strip_prefix("seeds:") else {
+        return Err(ParseError::at(header, header, "expected `seeds: <seed> <seed> ...`"));
+    };
+
+    seeds
+        .split_whitespace()
+        .map(|seed| {
+            seed.parse::<usize>()
+                .map_err(|_| ParseError::at(header, seed, format!("invalid seed `{seed}`")))
+        })
+        .collect()
+}
+
+/// Parses `seeds: <start> <length> ...` header of the almanac as ranges of seeds.
+///
+/// # Errors
+///
+/// Fails when header is malformed or its last range has no length.
+pub fn parse_seed_ranges(header: &str) -> Result<Vec<Range<usize>>, ParseError> {
+    let seeds = parse_seeds(header)?;
+    if seeds.len() % 2 == 1 {
+        let last = header.split_whitespace().last().unwrap_or(header);
+        return Err(ParseError::at(header, last, "seed range has no length"));
+    }
+
+    Ok(seeds
+        .chunks(2)
+        .map(|chunk| chunk[0]..(chunk[0] + chunk[1]))
+        .collect::<Vec<_>>())
+}
+
+/// Builds mapping from seed number to location number out of almanac maps
+/// (`seed-to-soil map:` ... `humidity-to-location map:`) separated by empty lines.
+///
+/// # Errors
+///
+/// Fails when any of the maps is malformed or missing, unless in lenient mode which drops malformed
+/// ranges. Maps of unknown categories are ignored.
+pub fn seed_to_location(maps: &str, diagnostics: &mut Diagnostics) -> Result<impl Fn(usize) -> usize, ParseError> {
+    let mut parsed = HashMap::new();
+    for map in maps.split("\n\n").filter(|map| !map.trim().is_empty()) {
+        let (name, ranges) = diagnostics.within(maps, map, |diagnostics| parse_map(map, diagnostics))?;
+
+        if MAP_NAMES.contains(&name) {
+            parsed.insert(name, ranges);
+        } else {
+            diagnostics.ignore(ParseError::at(maps, name, format!("unknown map `{name}`")))?;
+        }
+    }
+
+    let maps = MAP_NAMES
+        .iter()
+        .map(|name| {
+            parsed
+                .remove(name)
+                .ok_or_else(|| ParseError::end_of(maps, format!("missing `{name} map`")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(move |input: usize| {
+        maps.iter().fold(input, |value, mappings| {
+            mappings
+                .iter()
+                .find(|(src, _)| src.contains(&value))
+                .map_or(value, |(src, dst)| dst.start + (value - src.start))
+        })
+    })
+}
+
+/// Source ranges of a map together with destination ranges they are mapped to.
+type Mappings = Vec<(Range<usize>, Range<usize>)>;
+
+/// Parses `<name> map:` followed by `<destination> <source> <length>` lines.
+fn parse_map<'a>(map: &'a str, diagnostics: &mut Diagnostics) -> Result<(&'a str, Mappings), ParseError> {
+    let Some((name, ranges)) = map.split_once(':') else {
+        return Err(ParseError::at(map, map, "expected `<name> map:` followed by ranges"));
+    };
+
+    let ranges = ranges
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let numbers = line
+                .split_whitespace()
+                .map(|number| {
+                    number
+                        .parse::<usize>()
+                        .map_err(|_| ParseError::at(map, number, format!("invalid number `{number}`")))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let [dst, src, len] = numbers[..] else {
+                return Err(ParseError::at(map, line, "expected `<destination> <source> <length>`"));
+            };
+
+            Ok(((src..src + len), (dst..dst + len)))
+        })
+        .map(|range| diagnostics.record(range))
+        .filter_map(Result::transpose)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((name.trim().trim_end_matches(" map"), ranges))
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn test_lowest_location() {
+    let contents = include_str!("../test.txt");
+    let location = lowest_location(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(35, location);
+}
+
+#[test]
+fn test_lowest_range_location() {
+    let contents = include_str!("../test.txt");
+    let location = lowest_range_location(contents, &mut Diagnostics::default()).unwrap();
+
+    assert_eq!(46, location);
+}
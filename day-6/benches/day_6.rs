@@ -0,0 +1,81 @@
+use aoc_common::Diagnostics;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_6::{
+    calculate_distance_count, corrected_error_margin_product, error_margin_product,
+    parse_kerned_races, parse_races, Race,
+};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+/// Document with four races of real puzzle size. Its kerned race lasts over 50 million milliseconds.
+const SYNTHETIC: &str =
+    "Time:        56     97     78     75\nDistance:   546   1927   1131   1139\n";
+
+/// Many short races, each one with a record set by holding the button for a third of the race.
+fn races(count: usize) -> Vec<Race> {
+    (0..count)
+        .map(|i| {
+            let time = 50 + i % 50;
+            Race {
+                time,
+                distance: (time / 3) * (time - time / 3),
+            }
+        })
+        .collect()
+}
+
+fn parsers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-6/parse");
+
+    for (name, input) in [("example", EXAMPLE), ("synthetic", SYNTHETIC)] {
+        group.bench_with_input(BenchmarkId::new("parse_races", name), input, |b, input| {
+            b.iter(|| parse_races(black_box(input), &mut Diagnostics::default()));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("parse_kerned_races", name),
+            input,
+            |b, input| {
+                b.iter(|| parse_kerned_races(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-6");
+    // Part two simulates every millisecond of the kerned race.
+    group.sample_size(10);
+
+    let races = races(10_000);
+    group.bench_with_input(
+        BenchmarkId::new("calculate_distance_count", "synthetic"),
+        races.as_slice(),
+        |b, races| b.iter(|| calculate_distance_count(black_box(races))),
+    );
+
+    for (name, input) in [("example", EXAMPLE), ("synthetic", SYNTHETIC)] {
+        group.bench_with_input(
+            BenchmarkId::new("error_margin_product", name),
+            input,
+            |b, input| {
+                b.iter(|| error_margin_product(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("corrected_error_margin_product", name),
+            input,
+            |b, input| {
+                b.iter(|| {
+                    corrected_error_margin_product(black_box(input), &mut Diagnostics::default())
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parsers, solvers);
+criterion_main!(benches);
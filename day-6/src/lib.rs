@@ -0,0 +1,210 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+//! Day 6: Wait For It.
+//!
+//! Holding the boat button charges its speed. Every race is won by travelling further than the record.
+
+use aoc_common::{Answer, Diagnostics, ParseError, Solver, Span};
+use itertools::Itertools;
+
+/// Solver of day 6.
+pub struct Day6;
+
+impl Solver for Day6 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        error_margin_product(input, diagnostics).map(Into::into)
+    }
+
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        corrected_error_margin_product(input, diagnostics).map(Into::into)
+    }
+}
+
+/// Multiplies numbers of ways to beat the record of every race.
+///
+/// # Errors
+///
+/// Fails when document is not made of `Time:` and `Distance:` lines with a number for every race.
+/// Lenient mode drops malformed races instead.
+pub fn error_margin_product(
+    contents: &str,
+    diagnostics: &mut Diagnostics,
+) -> Result<usize, ParseError> {
+    let races = parse_races(contents, diagnostics)?;
+
+    Ok(calculate_distance_count(races.as_slice())
+        .into_iter()
+        .product())
+}
+
+/// Counts ways to beat the record of the single race written with bad kerning.
+///
+/// # Errors
+///
+/// Fails when document is not made of `Time:` and `Distance:` lines made of digits.
+pub fn corrected_error_margin_product(
+    contents: &str,
+    diagnostics: &mut Diagnostics,
+) -> Result<usize, ParseError> {
+    let races = parse_kerned_races(contents, diagnostics)?;
+
+    Ok(calculate_distance_count(races.as_slice())
+        .into_iter()
+        .product())
+}
+
+/// Parses `Time:` and `Distance:` lines into races, one per column.
+///
+/// # Errors
+///
+/// Fails when document is not made of `Time:` and `Distance:` lines with a number for every race.
+/// Lenient mode drops malformed races and columns without a match instead.
+pub fn parse_races(contents: &str, diagnostics: &mut Diagnostics) -> Result<Vec<Race>, ParseError> {
+    let (times, distances) = split_document(contents, diagnostics)?;
+
+    let unmatched = if times.len() > distances.len() {
+        &times[distances.len()..]
+    } else {
+        &distances[times.len()..]
+    };
+    for number in unmatched {
+        diagnostics.record(Err::<(), _>(ParseError::at(
+            contents,
+            number,
+            "race has no matching time or distance",
+        )))?;
+    }
+
+    let parse = |number: &&str| {
+        number
+            .parse::<usize>()
+            .map_err(|_| ParseError::at(contents, number, format!("invalid number `{number}`")))
+    };
+
+    times
+        .iter()
+        .zip(&distances)
+        .map(|(time, distance)| {
+            Ok(Race {
+                time: parse(time)?,
+                distance: parse(distance)?,
+            })
+        })
+        .map(|race| diagnostics.record(race))
+        .filter_map(Result::transpose)
+        .collect()
+}
+
+/// Parses `Time:` and `Distance:` lines ignoring spaces between digits, which yields a single race.
+///
+/// # Errors
+///
+/// Fails when document is not made of `Time:` and `Distance:` lines made of digits.
+pub fn parse_kerned_races(
+    contents: &str,
+    diagnostics: &mut Diagnostics,
+) -> Result<Vec<Race>, ParseError> {
+    let (times, distances) = split_document(contents, diagnostics)?;
+
+    let parse = |digits: &[&str]| {
+        if let Some(token) = digits
+            .iter()
+            .find(|token| !token.bytes().all(|byte| byte.is_ascii_digit()))
+        {
+            return Err(ParseError::at(
+                contents,
+                token,
+                format!("invalid number `{token}`"),
+            ));
+        }
+
+        match (digits.first(), digits.last()) {
+            (Some(first), Some(last)) => {
+                let span = Span::new(
+                    Span::of(contents, first).start,
+                    Span::of(contents, last).end,
+                );
+                digits
+                    .concat()
+                    .parse::<usize>()
+                    .map(Some)
+                    .map_err(|_| ParseError::new(span, "kerned number is too large"))
+            }
+            _ => Ok(None),
+        }
+    };
+
+    Ok(parse(&times)?
+        .zip(parse(&distances)?)
+        .map(|(time, distance)| Race { time, distance })
+        .into_iter()
+        .collect_vec())
+}
+
+/// Splits document into numbers of its `Time:` line and numbers of its `Distance:` line, skipping
+/// blank lines.
+fn split_document<'a>(
+    contents: &'a str,
+    diagnostics: &mut Diagnostics,
+) -> Result<(Vec<&'a str>, Vec<&'a str>), ParseError> {
+    let mut lines = Vec::new();
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            diagnostics.ignore(ParseError::at(contents, line, "unexpected blank line"))?;
+        } else {
+            lines.push(line);
+        }
+    }
+
+    let Some((times, distances)) = lines.into_iter().collect_tuple() else {
+        return Err(ParseError::at(
+            contents,
+            contents,
+            "expected `Time:` line followed by `Distance:` line",
+        ));
+    };
+
+    Ok((
+        line_numbers(contents, times, "Time:")?,
+        line_numbers(contents, distances, "Distance:")?,
+    ))
+}
+
+/// Whitespace separated numbers following `label` at the start of `line`.
+fn line_numbers<'a>(
+    contents: &str,
+    line: &'a str,
+    label: &str,
+) -> Result<Vec<&'a str>, ParseError> {
+    line.strip_prefix(label)
+        .map(|numbers| numbers.split_whitespace().collect_vec())
+        .ok_or_else(|| ParseError::at(contents, line, format!("expected `{label}` line")))
+}
+
+/// Counts, for every race, how many button hold times beat its record distance.
+#[must_use]
+pub fn calculate_distance_count(races: &[Race]) -> Vec<usize> {
+    races
+        .iter()
+        .map(|race| {
+            (0..=race.time)
+                .map(|time| (race.time - time) * time)
+                .filter(|distance| distance > &race.distance)
+                .count()
+        })
+        .collect_vec()
+}
+
+/// Race duration together with the record distance to beat.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Race {
+    pub time: usize,
+    pub distance: usize,
+}
+
+#[cfg(test)]
+mod test;
@@ -3,7 +3,7 @@ use super::*;
 #[test]
 fn test_error_margin_product() {
     let contents = include_str!("../test.txt");
-    let product = error_margin_product(contents);
+    let product = error_margin_product(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(288, product);
 }
@@ -11,7 +11,7 @@ fn test_error_margin_product() {
 #[test]
 fn test_corrected_error_margin_product() {
     let contents = include_str!("../test.txt");
-    let product = corrected_error_margin_product(contents);
+    let product = corrected_error_margin_product(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(71503, product);
 }
@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use aoc_common::Diagnostics;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_7::{modified_total_winnings, parts, total_winnings, Round};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Rounds with hands of every type, jokers included.
+fn synthetic(rounds: usize) -> String {
+    (0..rounds)
+        .map(|i| {
+            let hand = (0..5)
+                .map(|k| char::from(CARDS[(i / (k + 1) + k * i) % CARDS.len()]))
+                .collect::<String>();
+            format!("{hand} {}\n", 1 + (i * 37) % 1000)
+        })
+        .collect()
+}
+
+fn inputs() -> [(&'static str, String); 2] {
+    [("example", EXAMPLE.to_owned()), ("synthetic", synthetic(10_000))]
+}
+
+fn parsers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-7/parse");
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(BenchmarkId::new("Round::from_str", name), input, |b, input| {
+            b.iter(|| {
+                black_box(input)
+                    .lines()
+                    .map(Round::<parts::I>::from_str)
+                    .collect::<Vec<_>>()
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-7");
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(BenchmarkId::new("total_winnings", name), input, |b, input| {
+            b.iter(|| total_winnings(black_box(input), &mut Diagnostics::default()));
+        });
+        group.bench_with_input(BenchmarkId::new("modified_total_winnings", name), input, |b, input| {
+            b.iter(|| modified_total_winnings(black_box(input), &mut Diagnostics::default()));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parsers, solvers);
+criterion_main!(benches);
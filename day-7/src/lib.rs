@@ -0,0 +1,416 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+//! Day 7: Camel Cards.
+//!
+//! Hands are ranked by type and then card by card. In part two `J` cards are jokers: the weakest card
+//! which can pretend to be any other one. Rules of each part are selected by [`parts`] marker type.
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::{cmp::Ordering, convert::Infallible};
+
+use aoc_common::{Answer, Diagnostics, ParseError, Solver};
+use itertools::Itertools;
+use parts::Part;
+
+/// Solver of day 7.
+pub struct Day7;
+
+impl Solver for Day7 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        total_winnings(input, diagnostics).map(Into::into)
+    }
+
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        modified_total_winnings(input, diagnostics).map(Into::into)
+    }
+}
+
+/// Sums bids multiplied by rank of their hands.
+///
+/// # Errors
+///
+/// Fails on the first line which is not a valid round, unless in lenient mode which drops such lines.
+pub fn total_winnings(contents: &str, diagnostics: &mut Diagnostics) -> Result<usize, ParseError> {
+    fn parse_rounds(contents: &str, diagnostics: &mut Diagnostics) -> Result<Vec<Round<parts::I>>, ParseError> {
+        Ok(parse_lines(contents, diagnostics)?
+            .into_iter()
+            .sorted_by_cached_key(|round| round.hand.clone())
+            .collect())
+    }
+
+    let rounds = parse_rounds(contents, diagnostics)?;
+
+    Ok(rounds
+        .into_iter()
+        .enumerate()
+        .map(|(weight, round)| weight.saturating_add(1) * round.bid)
+        .sum::<usize>())
+}
+
+/// Sums bids multiplied by rank of their hands, treating `J` cards as jokers.
+///
+/// # Errors
+///
+/// Fails on the first line which is not a valid round, unless in lenient mode which drops such lines.
+pub fn modified_total_winnings(contents: &str, diagnostics: &mut Diagnostics) -> Result<usize, ParseError> {
+    fn parse_rounds(contents: &str, diagnostics: &mut Diagnostics) -> Result<Vec<Round<parts::II>>, ParseError> {
+        fn update_hand_type<P: Part>(hand: HandType<P>) -> HandType<P>
+        where
+            Card<P>: PartialOrd + Ord,
+        {
+            match hand {
+                HandType::HighCard(hand) => {
+                    if hand.cards.contains(&Card::Jack) {
+                        HandType::OnePair(hand)
+                    } else {
+                        HandType::HighCard(hand)
+                    }
+                }
+                HandType::OnePair(hand) => {
+                    if hand.cards.contains(&Card::Jack) {
+                        HandType::Three(hand)
+                    } else {
+                        HandType::OnePair(hand)
+                    }
+                }
+                HandType::TwoPairs(hand) => {
+                    if hand.cards.iter().filter(|card| matches!(card, Card::Jack)).count() == 2 {
+                        HandType::Four(hand)
+                    } else if hand.cards.contains(&Card::Jack) {
+                        HandType::FullHouse(hand)
+                    } else {
+                        HandType::TwoPairs(hand)
+                    }
+                }
+                HandType::Three(hand) => {
+                    if hand.cards.contains(&Card::Jack) {
+                        HandType::Four(hand)
+                    } else {
+                        HandType::Three(hand)
+                    }
+                }
+                HandType::FullHouse(hand) => {
+                    if hand.cards.contains(&Card::Jack) {
+                        HandType::Five(hand)
+                    } else {
+                        HandType::FullHouse(hand)
+                    }
+                }
+                HandType::Four(hand) => {
+                    if hand.cards.contains(&Card::Jack) {
+                        HandType::Five(hand)
+                    } else {
+                        HandType::Four(hand)
+                    }
+                }
+                hand @ HandType::Five(_) => hand,
+            }
+        }
+
+        Ok(parse_lines::<parts::II>(contents, diagnostics)?
+            .into_iter()
+            .map(|round| Round {
+                hand: update_hand_type(round.hand),
+                bid: round.bid,
+            })
+            .sorted_by_cached_key(|round| round.hand.clone())
+            .collect_vec())
+    }
+
+    let rounds = parse_rounds(contents, diagnostics)?;
+
+    Ok(rounds
+        .into_iter()
+        .enumerate()
+        .map(|(weight, round)| weight.saturating_add(1) * round.bid)
+        .sum::<usize>())
+}
+
+/// Parses every non-blank line as a round.
+fn parse_lines<P: Part>(contents: &str, diagnostics: &mut Diagnostics) -> Result<Vec<Round<P>>, ParseError>
+where
+    Card<P>: PartialOrd + Ord,
+{
+    let mut rounds = Vec::new();
+
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            diagnostics.ignore(ParseError::at(contents, line, "unexpected blank line"))?;
+            continue;
+        }
+
+        let round = Round::from_str(line).map_err(|error| error.within(contents, line));
+        rounds.extend(diagnostics.record(round)?);
+    }
+
+    Ok(rounds)
+}
+
+/// Single `<hand> <bid>` line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Round<P: Part>
+where
+    Card<P>: PartialOrd + Ord,
+{
+    pub hand: HandType<P>,
+    pub bid: usize,
+}
+
+impl<P: Part> FromStr for Round<P>
+where
+    Card<P>: PartialOrd + Ord,
+{
+    type Err = ParseError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let (cards, bid) = src
+            .split_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::at(src, src, "expected `<hand> <bid>`"))?;
+
+        let bid = bid
+            .parse::<usize>()
+            .map_err(|_| ParseError::at(src, bid, format!("invalid bid `{bid}`")))?;
+
+        let hand = cards
+            .char_indices()
+            .map(|(index, label)| {
+                Card::try_from(label).map_err(|_| {
+                    let card = &cards[index..index + label.len_utf8()];
+                    ParseError::at(src, card, format!("unknown card `{label}`"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .collect_tuple::<(Card<P>, Card<P>, Card<P>, Card<P>, Card<P>)>()
+            .ok_or_else(|| ParseError::at(src, cards, "hand has to have exactly five cards"))?;
+
+        let hand = Hand {
+            cards: [hand.0, hand.1, hand.2, hand.3, hand.4],
+        }
+        .into();
+
+        Ok(Round { hand, bid })
+    }
+}
+
+/// Hand classified by its type. Variants are ordered from the weakest to the strongest.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType<P: Part>
+where
+    Card<P>: PartialOrd + Ord,
+{
+    HighCard(Hand<P>),
+    OnePair(Hand<P>),
+    TwoPairs(Hand<P>),
+    Three(Hand<P>),
+    FullHouse(Hand<P>),
+    Four(Hand<P>),
+    Five(Hand<P>),
+}
+
+/// Five cards in order they were dealt.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hand<P: Part>
+where
+    Card<P>: PartialOrd + Ord,
+{
+    pub cards: [Card<P>; 5],
+}
+
+impl<P: Part> From<Hand<P>> for HandType<P>
+where
+    Card<P>: PartialOrd + Ord,
+{
+    fn from(hand: Hand<P>) -> Self {
+        let groups = hand
+            .cards
+            .clone()
+            .into_iter()
+            .into_group_map_by(std::mem::discriminant)
+            .into_values()
+            .map(|group| group.len())
+            .collect_vec();
+
+        match groups {
+            // If we have 5 groups it means we have 5 different cards in hand, hence High Card
+            group if group.len() == 5 => HandType::HighCard(hand),
+            // If we have 4 groups it means exactly one has 2 cards, hence One Pair
+            group if group.len() == 4 => HandType::OnePair(hand),
+            // If we have 3 groups and one of them is 3 then others must have 1, hence Three of a Kind
+            group if group.len() == 3 && group.contains(&3) => HandType::Three(hand),
+            // If we have 3 groups and two of them is 2 then other must have 1, hence Two Pairs
+            group if group.len() == 3 && group.iter().filter(|&&e| e == 2).count() == 2 => HandType::TwoPairs(hand),
+            // If we have 2 groups and one of them is 4 then other is 1, hence Four of a Kind
+            group if group.len() == 2 && group.contains(&4) => HandType::Four(hand),
+            // If we have 2 groups and none of them is 4 then one must be 3 and other 2, hence Full House
+            group if group.len() == 2 => HandType::FullHouse(hand),
+            // If we have only one group, only option is 5, hence Five of a Kind
+            _ => HandType::Five(hand),
+        }
+    }
+}
+
+/// Card label. Its strength depends on the rules of part `P`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Card<P: Part>
+where
+    Card<P>: PartialOrd + Ord,
+{
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+    #[allow(unused)]
+    Unreachable(Infallible, PhantomData<P>),
+}
+
+impl<P: Part> TryFrom<char> for Card<P>
+where
+    Card<P>: PartialOrd + Ord,
+{
+    /// Label which is not a card.
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'A' => Ok(Self::Ace),
+            'K' => Ok(Self::King),
+            'Q' => Ok(Self::Queen),
+            'J' => Ok(Self::Jack),
+            'T' => Ok(Self::Ten),
+            '9' => Ok(Self::Nine),
+            '8' => Ok(Self::Eight),
+            '7' => Ok(Self::Seven),
+            '6' => Ok(Self::Six),
+            '5' => Ok(Self::Five),
+            '4' => Ok(Self::Four),
+            '3' => Ok(Self::Three),
+            '2' => Ok(Self::Two),
+            card => Err(card),
+        }
+    }
+}
+
+impl Ord for Card<parts::I> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let me = unsafe { *<*const _>::from(self).cast::<u8>() };
+        let other = unsafe { *<*const _>::from(other).cast::<u8>() };
+
+        me.cmp(&other)
+    }
+}
+
+impl PartialOrd for Card<parts::I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Card<parts::II> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self {
+            Card::Jack => match other {
+                Card::Jack => Ordering::Equal,
+                _ => Ordering::Less,
+            },
+            Card::Two => match other {
+                Card::Jack => Ordering::Greater,
+                Card::Two => Ordering::Equal,
+                _ => Ordering::Less,
+            },
+            Card::Three => match other {
+                Card::Jack | Card::Two => Ordering::Greater,
+                Card::Three => Ordering::Equal,
+                _ => Ordering::Less,
+            },
+            Card::Four => match other {
+                Card::Jack | Card::Two | Card::Three => Ordering::Greater,
+                Card::Four => Ordering::Equal,
+                _ => Ordering::Less,
+            },
+            Card::Five => match other {
+                Card::Jack | Card::Two | Card::Three | Card::Four => Ordering::Greater,
+                Card::Five => Ordering::Equal,
+                _ => Ordering::Less,
+            },
+            Card::Six => match other {
+                Card::Jack | Card::Two | Card::Three | Card::Four | Card::Five => Ordering::Greater,
+                Card::Six => Ordering::Equal,
+                _ => Ordering::Less,
+            },
+            Card::Seven => match other {
+                Card::Jack | Card::Two | Card::Three | Card::Four | Card::Five | Card::Six => Ordering::Greater,
+                Card::Seven => Ordering::Equal,
+                _ => Ordering::Less,
+            },
+            Card::Eight => match other {
+                Card::Nine | Card::Ten | Card::Queen | Card::King | Card::Ace => Ordering::Less,
+                Card::Eight => Ordering::Equal,
+                _ => Ordering::Greater,
+            },
+            Card::Nine => match other {
+                Card::Ten | Card::Queen | Card::King | Card::Ace => Ordering::Less,
+                Card::Nine => Ordering::Equal,
+                _ => Ordering::Greater,
+            },
+            Card::Ten => match other {
+                Card::Queen | Card::King | Card::Ace => Ordering::Less,
+                Card::Ten => Ordering::Equal,
+                _ => Ordering::Greater,
+            },
+            Card::Queen => match other {
+                Card::King | Card::Ace => Ordering::Less,
+                Card::Queen => Ordering::Equal,
+                _ => Ordering::Greater,
+            },
+            Card::King => match other {
+                Card::Ace => Ordering::Less,
+                Card::King => Ordering::Equal,
+                _ => Ordering::Greater,
+            },
+            Card::Ace => match other {
+                Card::Ace => Ordering::Equal,
+                _ => Ordering::Greater,
+            },
+            Card::Unreachable(..) => unreachable!(),
+        }
+    }
+}
+
+impl PartialOrd for Card<parts::II> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Marker types selecting rules of the puzzle part.
+pub mod parts {
+    pub trait Part: PartialEq + Eq + PartialOrd + Ord + Clone {}
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct I;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct II;
+
+    impl Part for I {}
+    impl Part for II {}
+}
+
+#[cfg(test)]
+mod test;
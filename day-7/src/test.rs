@@ -3,7 +3,7 @@ use super::*;
 #[test]
 fn test_total_winnings() {
     let contents = include_str!("../test.txt");
-    let winnings = total_winnings(contents);
+    let winnings = total_winnings(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(6440, winnings);
 }
@@ -11,7 +11,7 @@ fn test_total_winnings() {
 #[test]
 fn test_modified_total_winnings() {
     let contents = include_str!("../test.txt");
-    let winnings = modified_total_winnings(contents);
+    let winnings = modified_total_winnings(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(5905, winnings);
-}
\ No newline at end of file
+}
@@ -0,0 +1,83 @@
+use std::str::FromStr;
+
+use aoc_common::Diagnostics;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_8::{simultaneous_step_count, step_count, Map};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+/// Cycle lengths of ghost paths. The first one leads from `AAA` to `ZZZ`.
+const CYCLES: [usize; 6] = [101, 103, 107, 109, 113, 127];
+
+/// Network of ghost paths, each a cycle from its `..A` node through its `..Z` node, padded with
+/// `fillers` unreachable nodes.
+fn synthetic(fillers: usize) -> String {
+    let moves = (0..263)
+        .map(|i| if i * 7 % 3 == 0 { 'L' } else { 'R' })
+        .collect::<String>();
+
+    let id = |cycle: usize, step: usize, len: usize| match (cycle, step) {
+        (0, 0) => "AAA".to_owned(),
+        (0, _) if step == len => "ZZZ".to_owned(),
+        (_, 0) => format!("{cycle:02}A"),
+        _ if step == len => format!("{cycle:02}Z"),
+        _ => format!("{cycle:02}{step:03}"),
+    };
+    let paths = CYCLES.iter().enumerate().flat_map(|(cycle, &len)| {
+        (0..=len).map(move |step| {
+            let next = id(cycle, if step == len { 1 } else { step + 1 }, len);
+            format!("{} = ({next}, {next})", id(cycle, step, len))
+        })
+    });
+    let fillers = (0..fillers).map(|i| format!("F{i:05} = (F{i:05}, F{i:05})"));
+
+    format!(
+        "{moves}\n\n{}\n",
+        paths.chain(fillers).collect::<Vec<_>>().join("\n")
+    )
+}
+
+fn inputs() -> [(&'static str, String); 2] {
+    [
+        ("example", EXAMPLE.to_owned()),
+        ("synthetic", synthetic(10_000)),
+    ]
+}
+
+fn parsers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-8/parse");
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(
+            BenchmarkId::new("Map::from_str", name),
+            input,
+            |b, input| {
+                b.iter(|| Map::from_str(black_box(input)));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-8");
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(BenchmarkId::new("step_count", name), input, |b, input| {
+            b.iter(|| step_count(black_box(input), &mut Diagnostics::default()));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("simultaneous_step_count", name),
+            input,
+            |b, input| {
+                b.iter(|| simultaneous_step_count(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parsers, solvers);
+criterion_main!(benches);
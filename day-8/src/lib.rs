@@ -0,0 +1,342 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+//! Day 8: Haunted Wasteland.
+//!
+//! Network of nodes is walked following repeating left/right instructions.
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_common::{Answer, Diagnostics, ParseError, Solver};
+use itertools::Itertools;
+use num_integer::lcm as lowest_common_multiple;
+use rayon::prelude::*;
+
+/// Solver of day 8.
+pub struct Day8;
+
+impl Solver for Day8 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        step_count(input, diagnostics).map(Into::into)
+    }
+
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        simultaneous_step_count(input, diagnostics).map(Into::into)
+    }
+}
+
+/// Counts steps needed to get from `AAA` node to `ZZZ` node.
+///
+/// # Errors
+///
+/// Fails when map is malformed, has no `AAA` node or `ZZZ` node cannot be reached from it.
+pub fn step_count(contents: &str, diagnostics: &mut Diagnostics) -> Result<usize, ParseError> {
+    let map = Map::parse(contents, diagnostics)?;
+    // Walk longer than this has to be going in circles.
+    let limit = map.nodes.len() * map.moves.scheme.len();
+
+    let mut steps = 0;
+    let mut node = map
+        .nodes
+        .get(&Id("AAA".to_owned()))
+        .ok_or_else(|| ParseError::end_of(contents, "map has no `AAA` node"))?;
+    // Every node and direction was validated when parsing the map.
+    for direction in map.moves {
+        steps += 1;
+        match direction {
+            Direction::Left => node = &map.nodes[&node.left],
+            Direction::Right => node = &map.nodes[&node.right],
+        }
+
+        if node.id.0.as_str() == "ZZZ" {
+            return Ok(steps);
+        }
+        if steps > limit {
+            break;
+        }
+    }
+
+    Err(ParseError::end_of(
+        contents,
+        "`ZZZ` node cannot be reached from `AAA` node",
+    ))
+}
+
+/// Counts steps after which paths started from all `..A` nodes stand on `..Z` nodes at once.
+///
+/// # Errors
+///
+/// Fails when map is malformed or path started from any `..A` node never reaches a `..Z` node.
+pub fn simultaneous_step_count(
+    contents: &str,
+    diagnostics: &mut Diagnostics,
+) -> Result<usize, ParseError> {
+    // Finding common end path could take forever going with naive solution.
+    // Fortunately, we can calculate indices of ending points for every path
+    // and then just find lowest common multiple of them!
+
+    let map = Map::parse(contents, diagnostics)?;
+
+    let moves = map.moves.into_iter();
+    let nodes = map
+        .nodes
+        .values()
+        .par_bridge()
+        .filter(|node| node.is_origin())
+        .map(|origin| {
+            let mut path = Vec::new();
+            let moves = moves.clone();
+
+            let mut node = origin;
+            let len = moves.scheme.len();
+
+            for (index, direction) in moves.enumerate() {
+                match direction {
+                    Direction::Left => {
+                        node = &map.nodes[&node.left];
+                        if node.is_final() {
+                            path.push((index + 1, node.clone()));
+                        }
+                    }
+                    Direction::Right => {
+                        node = &map.nodes[&node.right];
+                        if node.is_final() {
+                            path.push((index + 1, node.clone()));
+                        }
+                    }
+                }
+
+                if !path.is_empty() || index > (len * len) {
+                    break;
+                }
+            }
+
+            let Some(last) = path.last().cloned() else {
+                let message = format!(
+                    "path from `{}` node never reaches any `..Z` node",
+                    origin.id.0
+                );
+                return Err(match definition(contents, &origin.id) {
+                    Some(line) => ParseError::at(contents, line, message),
+                    None => ParseError::end_of(contents, message),
+                });
+            };
+            path.push(last);
+
+            Ok(path)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let steps = nodes
+        .iter()
+        .map(|path| path.iter().map(|&(index, _)| index))
+        .multi_cartesian_product()
+        .map(|indices| {
+            indices
+                .into_iter()
+                .reduce(lowest_common_multiple)
+                .unwrap_or_default()
+        })
+        .min()
+        .unwrap_or_default();
+
+    Ok(steps)
+}
+
+/// Line of the network defining the node.
+fn definition<'a>(contents: &'a str, id: &Id) -> Option<&'a str> {
+    contents
+        .lines()
+        .find(|line| line.split_once(" = ").is_some_and(|(node, _)| node == id.0))
+}
+
+/// Instructions together with the network they navigate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Map {
+    pub moves: Moves,
+    pub nodes: HashMap<Id, Node>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    /// Parses instructions and network separated by an empty line, see [`Map::parse`].
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::parse(src, &mut Diagnostics::default())
+    }
+}
+
+impl Map {
+    /// Parses instructions and network separated by an empty line.
+    ///
+    /// Every node referred to as a neighbour has to be defined in the network.
+    ///
+    /// # Errors
+    ///
+    /// Fails when instructions are malformed, any node refers to a missing one or any node entry is
+    /// malformed, unless in lenient mode which drops such entries.
+    pub fn parse(src: &str, diagnostics: &mut Diagnostics) -> Result<Self, ParseError> {
+        let (moves, nodes) = src.split_once("\n\n").ok_or_else(|| {
+            ParseError::end_of(src, "expected instructions, empty line and nodes")
+        })?;
+
+        if moves.is_empty() {
+            return Err(ParseError::at(
+                src,
+                moves,
+                "expected at least one instruction",
+            ));
+        }
+        let moves = moves
+            .char_indices()
+            .map(|(index, direction)| match direction {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                unknown => Err(ParseError::at(
+                    src,
+                    &moves[index..index + unknown.len_utf8()],
+                    format!("direction can be only `L` or `R`, `{unknown}` is neither of them"),
+                )),
+            })
+            .collect::<Result<Vec<Direction>, ParseError>>()?;
+
+        let moves = Moves { scheme: moves };
+
+        let mut entries = Vec::new();
+        for line in nodes.lines() {
+            if line.trim().is_empty() {
+                diagnostics.ignore(ParseError::at(src, line, "unexpected blank line"))?;
+                continue;
+            }
+
+            let entry = parse_node(line).map_err(|error| error.within(src, line));
+            entries.extend(diagnostics.record(entry)?);
+        }
+
+        let nodes = entries
+            .iter()
+            .map(|(node, ..)| (node.id.clone(), node.clone()))
+            .collect::<HashMap<Id, Node>>();
+
+        for (node, left, right) in &entries {
+            for (id, fragment) in [(&node.left, left), (&node.right, right)] {
+                if !nodes.contains_key(id) {
+                    return Err(ParseError::at(
+                        src,
+                        fragment,
+                        format!("node `{}` doesn't exist", id.0),
+                    ));
+                }
+            }
+        }
+
+        Ok(Self { moves, nodes })
+    }
+}
+
+/// Parses `<id> = (<left>, <right>)` entry, returning fragments of neighbour ids along with the node.
+fn parse_node(line: &str) -> Result<(Node, &str, &str), ParseError> {
+    let (id, neighbors) = line
+        .split_once(" = ")
+        .ok_or_else(|| ParseError::at(line, line, "expected `<id> = (<left>, <right>)`"))?;
+    let (left, right) = neighbors
+        .strip_prefix('(')
+        .and_then(|neighbors| neighbors.strip_suffix(')'))
+        .and_then(|neighbors| neighbors.split_once(','))
+        .map(|(left, right)| (left.trim(), right.trim()))
+        .ok_or_else(|| ParseError::at(line, neighbors, "expected `(<left>, <right>)`"))?;
+
+    let parse = |id: &str| Id::from_str(id).map_err(|error| error.within(line, id));
+    let node = Node {
+        id: parse(id)?,
+        left: parse(left)?,
+        right: parse(right)?,
+    };
+
+    Ok((node, left, right))
+}
+
+/// `<id> = (<left>, <right>)` network entry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub id: Id,
+    pub left: Id,
+    pub right: Id,
+}
+
+impl Node {
+    /// Whether ghost path starts at this node.
+    #[must_use]
+    pub fn is_origin(&self) -> bool {
+        self.id.0.ends_with('A')
+    }
+
+    /// Whether ghost path may end at this node.
+    #[must_use]
+    pub fn is_final(&self) -> bool {
+        self.id.0.ends_with('Z')
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Id(pub String);
+
+impl FromStr for Id {
+    type Err = ParseError;
+
+    /// Parses non-empty alphanumeric node id.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        if src.is_empty() || !src.chars().all(char::is_alphanumeric) {
+            return Err(ParseError::at(src, src, format!("invalid node id `{src}`")));
+        }
+
+        Ok(Self(src.to_owned()))
+    }
+}
+
+/// Left/right instructions. Iterating over them repeats the whole sequence endlessly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Moves {
+    pub scheme: Vec<Direction>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MoveIterator {
+    index: usize,
+    scheme: Vec<Direction>,
+}
+
+impl Iterator for MoveIterator {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.scheme.len();
+        let index = self.index;
+        self.index = (index + 1) % len;
+        self.scheme.get(index).copied()
+    }
+}
+
+impl IntoIterator for Moves {
+    type Item = Direction;
+
+    type IntoIter = MoveIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Self::IntoIter {
+            index: 0,
+            scheme: self.scheme,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[cfg(test)]
+mod test;
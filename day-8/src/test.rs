@@ -3,12 +3,12 @@ use super::*;
 #[test]
 fn test_step_count() {
     let contents = include_str!("../test.txt");
-    let steps = step_count(contents);
+    let steps = step_count(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(steps, 2);
 
     let contents = include_str!("../test_2.txt");
-    let steps = step_count(contents);
+    let steps = step_count(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(steps, 6);
 }
@@ -16,12 +16,30 @@ fn test_step_count() {
 #[test]
 fn test_simultaneous_step_count() {
     let contents = include_str!("../test.txt");
-    let steps = simultaneous_step_count(contents);
+    let steps = simultaneous_step_count(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(steps, 2);
 
     let contents = include_str!("../test_3.txt");
-    let steps = simultaneous_step_count(contents);
+    let steps = simultaneous_step_count(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(steps, 6);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_map_refers_to_missing_node() {
+    let contents = "LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+    let error = Map::from_str(contents).unwrap_err();
+
+    assert_eq!(&contents[error.span.start..error.span.end], "BBB");
+    assert_eq!(error.span.line_column(contents), (3, 8));
+}
+
+#[test]
+fn test_path_never_reaching_end_points_at_origin() {
+    let contents = "LR\n\n11A = (XXX, XXX)\nXXX = (XXX, XXX)\n22A = (22Z, 22Z)\n22Z = (22A, 22A)\n";
+    let error = simultaneous_step_count(contents, &mut Diagnostics::default()).unwrap_err();
+
+    assert!(error.message.contains("`11A`"), "{}", error.message);
+    assert_eq!(error.span.line_column(contents), (3, 1));
+}
@@ -0,0 +1,84 @@
+use aoc_common::Diagnostics;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_9::{generate_diff_list, parse_list, sum_of_predecessors, sum_of_successors};
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+/// Histories of 21 values of polynomials of degree up to 5, as in real puzzle input.
+fn synthetic(histories: usize) -> String {
+    (0..histories)
+        .map(|i| {
+            let coefficients = (0..=i % 6)
+                .map(|k| i64::try_from((i * 7 + k * 3) % 11).unwrap() - 5)
+                .collect::<Vec<_>>();
+            let values = (0..21_i64)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |value, coefficient| value * x + coefficient)
+                        .to_string()
+                })
+                .collect::<Vec<_>>();
+            format!("{}\n", values.join(" "))
+        })
+        .collect()
+}
+
+fn inputs() -> [(&'static str, String); 2] {
+    [
+        ("example", EXAMPLE.to_owned()),
+        ("synthetic", synthetic(10_000)),
+    ]
+}
+
+fn parsers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-9/parse");
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(BenchmarkId::new("parse_list", name), input, |b, input| {
+            b.iter(|| parse_list(black_box(input), &mut Diagnostics::default()));
+        });
+        let lists = parse_list(input, &mut Diagnostics::default()).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("generate_diff_list", name),
+            &lists,
+            |b, lists| {
+                b.iter(|| {
+                    black_box(lists)
+                        .iter()
+                        .map(|list| generate_diff_list(list))
+                        .collect::<Vec<_>>()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-9");
+
+    for (name, input) in &inputs() {
+        group.bench_with_input(
+            BenchmarkId::new("sum_of_successors", name),
+            input,
+            |b, input| {
+                b.iter(|| sum_of_successors(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("sum_of_predecessors", name),
+            input,
+            |b, input| {
+                b.iter(|| sum_of_predecessors(black_box(input), &mut Diagnostics::default()));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parsers, solvers);
+criterion_main!(benches);
@@ -0,0 +1,127 @@
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+//! Day 9: Mirage Maintenance.
+//!
+//! Every line is a history of values extrapolated with repeated differences until they are all zero.
+
+use aoc_common::{Answer, Diagnostics, ParseError, Solver};
+use itertools::Itertools;
+
+/// Solver of day 9.
+pub struct Day9;
+
+impl Solver for Day9 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part_one(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        sum_of_successors(input, diagnostics).map(Into::into)
+    }
+
+    fn part_two(&self, input: &str, diagnostics: &mut Diagnostics) -> Result<Answer, ParseError> {
+        sum_of_predecessors(input, diagnostics).map(Into::into)
+    }
+}
+
+/// Sums previous values extrapolated for every history.
+///
+/// # Errors
+///
+/// Fails when any of the histories has a value which is not a number, unless in lenient mode which
+/// drops such histories.
+pub fn sum_of_predecessors(
+    contents: &str,
+    diagnostics: &mut Diagnostics,
+) -> Result<i64, ParseError> {
+    let lists = parse_list(contents, diagnostics)?;
+
+    // Histories are never empty, parse_list() rejects them.
+    Ok(lists
+        .into_iter()
+        .map(|list| {
+            let mut firsts = vec![list[0]];
+            let mut current = generate_diff_list(&list);
+
+            // We generate difference lists until there's no difference between elements.
+            while !current.iter().all(|num| num == &0) {
+                firsts.push(current[0]);
+                current = generate_diff_list(current.as_slice());
+            }
+
+            firsts
+                .into_iter()
+                .rev()
+                .reduce(|last, curr| curr - last)
+                .unwrap_or_default()
+        })
+        .sum::<i64>())
+}
+
+/// Sums next values extrapolated for every history.
+///
+/// # Errors
+///
+/// Fails when any of the histories has a value which is not a number, unless in lenient mode which
+/// drops such histories.
+pub fn sum_of_successors(contents: &str, diagnostics: &mut Diagnostics) -> Result<i64, ParseError> {
+    let lists = parse_list(contents, diagnostics)?;
+
+    // Histories are never empty, parse_list() rejects them.
+    Ok(lists
+        .into_iter()
+        .map(|list| {
+            let mut last_elements = vec![list[list.len() - 1]];
+            let mut current = generate_diff_list(&list);
+
+            // We generate difference lists until there's no difference between elements.
+            while !current.iter().all(|num| num == &0) {
+                last_elements.push(current[current.len() - 1]);
+                current = generate_diff_list(current.as_slice());
+            }
+
+            last_elements.into_iter().sum::<i64>()
+        })
+        .sum::<i64>())
+}
+
+/// Parses one history of whitespace separated values per line. Blank lines hold no history.
+///
+/// # Errors
+///
+/// Fails when any of the histories has a value which is not a number, unless in lenient mode which
+/// drops such histories.
+pub fn parse_list(
+    contents: &str,
+    diagnostics: &mut Diagnostics,
+) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut lists = Vec::new();
+
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            diagnostics.ignore(ParseError::at(contents, line, "history has no values"))?;
+            continue;
+        }
+
+        let values = line
+            .split_whitespace()
+            .map(|number| {
+                number.parse::<i64>().map_err(|_| {
+                    ParseError::at(contents, number, format!("invalid value `{number}`"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>();
+        lists.extend(diagnostics.record(values)?);
+    }
+
+    Ok(lists)
+}
+
+/// Computes differences between consecutive values.
+#[must_use]
+pub fn generate_diff_list(list: &[i64]) -> Vec<i64> {
+    list.windows(2).map(|pair| pair[1] - pair[0]).collect_vec()
+}
+
+#[cfg(test)]
+mod test;
@@ -3,7 +3,7 @@ use super::*;
 #[test]
 fn test_find_sum_of_successors() {
     let contents = include_str!("../test.txt");
-    let value = sum_of_successors(contents);
+    let value = sum_of_successors(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(114, value);
 }
@@ -11,7 +11,7 @@ fn test_find_sum_of_successors() {
 #[test]
 fn test_find_sum_of_predecessors() {
     let contents = include_str!("../test.txt");
-    let value = sum_of_predecessors(contents);
+    let value = sum_of_predecessors(contents, &mut Diagnostics::default()).unwrap();
 
     assert_eq!(2, value);
-}
\ No newline at end of file
+}